@@ -0,0 +1,258 @@
+/// Declares the admin account composites shared by the liquidity layer programs: `Usdc`,
+/// `CheckedCustodian`, `OwnerOnly`, `OwnerOnlyMut`, `Admin` and `AdminMut`, plus a pair of
+/// composites for each [Role](crate::admin::Role) (e.g. `PauserOnly` and `PauserOnlyMut`).
+///
+/// The composites are parameterised over the program's custodian and role registry accounts. Admin
+/// check failures are reported with the program's error type, which must implement
+/// [AdminError](crate::admin::AdminError).
+///
+/// Anchor requires account composites to be declared in the crate of the program using them, so
+/// these are generated by this macro instead of being generic structs. Invoke it once from the
+/// program's composite module, where the custodian, role registry and error types are in scope.
+#[macro_export]
+macro_rules! admin_composites {
+    ($custodian:ident, $role_registry:ident, $error:ident) => {
+        mod __admin_composites {
+            use super::{$custodian, $error, $role_registry};
+            use anchor_lang::prelude::*;
+            use std::ops::Deref;
+            use $crate::{
+                admin::{
+                    utils::{assistant::only_authorized, multisig::only_owner_set, roles::only_role},
+                    AdminError, Role,
+                },
+                USDC_MINT,
+            };
+
+            #[derive(Accounts)]
+            pub struct Usdc<'info> {
+                /// CHECK: This address must equal [USDC_MINT](common::USDC_MINT).
+                #[account(address = USDC_MINT)]
+                pub mint: UncheckedAccount<'info>,
+            }
+
+            impl<'info> Deref for Usdc<'info> {
+                type Target = UncheckedAccount<'info>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.mint
+                }
+            }
+
+            #[derive(Accounts)]
+            pub struct CheckedCustodian<'info> {
+                #[account(
+                    seeds = [$custodian::SEED_PREFIX],
+                    bump = $custodian::BUMP,
+                )]
+                pub custodian: Box<Account<'info, $custodian>>,
+            }
+
+            impl<'info> Deref for CheckedCustodian<'info> {
+                type Target = Account<'info, $custodian>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.custodian
+                }
+            }
+
+            #[derive(Accounts)]
+            pub struct OwnerOnly<'info> {
+                #[account(
+                    constraint = only_owner_set(
+                        &custodian,
+                        &owner,
+                        instructions_sysvar.as_deref(),
+                        $error::owner_only()
+                    )?
+                )]
+                pub owner: Signer<'info>,
+
+                /// Required if the owner set is active. The transaction must be signed by enough
+                /// members of the owner set, which are passed in as remaining accounts.
+                ///
+                /// CHECK: Must equal instructions sysvar ID.
+                #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+                pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+                pub custodian: CheckedCustodian<'info>,
+            }
+
+            #[derive(Accounts)]
+            pub struct OwnerOnlyMut<'info> {
+                #[account(
+                    constraint = only_owner_set(
+                        &custodian,
+                        &owner,
+                        instructions_sysvar.as_deref(),
+                        $error::owner_only()
+                    )?
+                )]
+                pub owner: Signer<'info>,
+
+                /// Required if the owner set is active. The transaction must be signed by enough
+                /// members of the owner set, which are passed in as remaining accounts.
+                ///
+                /// CHECK: Must equal instructions sysvar ID.
+                #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+                pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+                #[account(
+                    mut,
+                    seeds = [$custodian::SEED_PREFIX],
+                    bump = $custodian::BUMP,
+                )]
+                pub custodian: Box<Account<'info, $custodian>>,
+            }
+
+            #[derive(Accounts)]
+            pub struct Admin<'info> {
+                #[account(
+                    constraint = only_authorized(
+                        &custodian,
+                        &owner_or_assistant,
+                        instructions_sysvar.as_deref(),
+                        $error::owner_or_assistant_only()
+                    )?
+                )]
+                pub owner_or_assistant: Signer<'info>,
+
+                /// Required if the owner set is active and the signer is the owner or a member of
+                /// the owner set. The transaction must be signed by enough members of the owner
+                /// set, which are passed in as remaining accounts.
+                ///
+                /// CHECK: Must equal instructions sysvar ID.
+                #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+                pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+                pub custodian: CheckedCustodian<'info>,
+            }
+
+            #[derive(Accounts)]
+            pub struct AdminMut<'info> {
+                #[account(
+                    constraint = only_authorized(
+                        &custodian,
+                        &owner_or_assistant,
+                        instructions_sysvar.as_deref(),
+                        $error::owner_or_assistant_only()
+                    )?
+                )]
+                pub owner_or_assistant: Signer<'info>,
+
+                /// Required if the owner set is active and the signer is the owner or a member of
+                /// the owner set. The transaction must be signed by enough members of the owner
+                /// set, which are passed in as remaining accounts.
+                ///
+                /// CHECK: Must equal instructions sysvar ID.
+                #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+                pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+                #[account(
+                    mut,
+                    seeds = [$custodian::SEED_PREFIX],
+                    bump = $custodian::BUMP,
+                )]
+                pub custodian: Box<Account<'info, $custodian>>,
+            }
+
+            $crate::__role_composites!(
+                $custodian,
+                $role_registry,
+                $error,
+                Pauser => PauserOnly, PauserOnlyMut;
+                EndpointManager => EndpointManagerOnly, EndpointManagerOnlyMut;
+                FeeManager => FeeManagerOnly, FeeManagerOnlyMut;
+                ParameterProposer => ParameterProposerOnly, ParameterProposerOnlyMut;
+            );
+        }
+
+        pub use __admin_composites::*;
+    };
+}
+
+/// Declares the composites requiring a specific role for [admin_composites]. Each role gets its
+/// own composites so the role is always checked by the composite itself.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __role_composites {
+    (
+        $custodian:ident,
+        $role_registry:ident,
+        $error:ident,
+        $($role:ident => $name:ident, $name_mut:ident;)+
+    ) => {
+        $(
+            #[derive(Accounts)]
+            pub struct $name<'info> {
+                /// Owner, owner assistant or a member of the role registry granted this role.
+                #[account(
+                    constraint = only_role(
+                        &custodian,
+                        roles.as_deref(),
+                        &authority,
+                        instructions_sysvar.as_deref(),
+                        Role::$role,
+                        $error::missing_role()
+                    )?
+                )]
+                pub authority: Signer<'info>,
+
+                /// Required if the owner set is active and the signer is the owner or a member of
+                /// the owner set. The transaction must be signed by enough members of the owner
+                /// set, which are passed in as remaining accounts.
+                ///
+                /// CHECK: Must equal instructions sysvar ID.
+                #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+                pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+                pub custodian: CheckedCustodian<'info>,
+
+                /// Required if the signer is neither the owner nor the owner assistant.
+                #[account(
+                    seeds = [$role_registry::SEED_PREFIX],
+                    bump = roles.bump,
+                )]
+                pub roles: Option<Box<Account<'info, $role_registry>>>,
+            }
+
+            #[derive(Accounts)]
+            pub struct $name_mut<'info> {
+                /// Owner, owner assistant or a member of the role registry granted this role.
+                #[account(
+                    constraint = only_role(
+                        &custodian,
+                        roles.as_deref(),
+                        &authority,
+                        instructions_sysvar.as_deref(),
+                        Role::$role,
+                        $error::missing_role()
+                    )?
+                )]
+                pub authority: Signer<'info>,
+
+                /// Required if the owner set is active and the signer is the owner or a member of
+                /// the owner set. The transaction must be signed by enough members of the owner
+                /// set, which are passed in as remaining accounts.
+                ///
+                /// CHECK: Must equal instructions sysvar ID.
+                #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+                pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+                #[account(
+                    mut,
+                    seeds = [$custodian::SEED_PREFIX],
+                    bump = $custodian::BUMP,
+                )]
+                pub custodian: Box<Account<'info, $custodian>>,
+
+                /// Required if the signer is neither the owner nor the owner assistant.
+                #[account(
+                    seeds = [$role_registry::SEED_PREFIX],
+                    bump = roles.bump,
+                )]
+                pub roles: Option<Box<Account<'info, $role_registry>>>,
+            }
+        )+
+    };
+}
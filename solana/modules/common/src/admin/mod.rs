@@ -1,6 +1,91 @@
+mod composite;
+
 pub mod utils;
 
-use anchor_lang::prelude::Pubkey;
+use anchor_lang::prelude::*;
+
+/// Maximum number of signers in an [OwnerSet].
+pub const MAX_OWNER_SET_SIGNERS: usize = 10;
+
+/// M-of-N set of signers allowed to act as the owner. If the threshold is zero, the set is inactive
+/// and only the single owner key is checked.
+///
+/// NOTE: Signers are stored in a fixed-size array so this struct always has the same serialized
+/// size, which keeps the offsets of the custodian fields after it fixed.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, InitSpace)]
+pub struct OwnerSet {
+    /// Number of distinct signers from this set required to approve an owner-only instruction.
+    pub threshold: u8,
+
+    /// Number of signers in this set. Only the first `num_signers` keys of `signers` are members.
+    pub num_signers: u8,
+    pub signers: [Pubkey; MAX_OWNER_SET_SIGNERS],
+}
+
+impl OwnerSet {
+    /// Create an owner set from a list of signers. Returns `None` if there are too many signers.
+    pub fn new(threshold: u8, signers: &[Pubkey]) -> Option<Self> {
+        let num_signers = u8::try_from(signers.len()).ok()?;
+        let mut owner_set = Self {
+            threshold,
+            num_signers,
+            ..Default::default()
+        };
+        owner_set
+            .signers
+            .get_mut(..signers.len())?
+            .copy_from_slice(signers);
+
+        Some(owner_set)
+    }
+
+    /// Members of this set.
+    pub fn members(&self) -> &[Pubkey] {
+        self.signers
+            .get(..usize::from(self.num_signers))
+            .unwrap_or_default()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.threshold > 0
+    }
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members().contains(key)
+    }
+
+    /// An inactive set must be empty. Otherwise the threshold cannot exceed the number of signers,
+    /// which must be unique and nonzero. Unused signer slots must be zero.
+    pub fn is_valid(&self) -> bool {
+        if !self.is_active() {
+            return *self == Self::default();
+        }
+
+        let members = self.members();
+        usize::from(self.num_signers) <= MAX_OWNER_SET_SIGNERS
+            && usize::from(self.threshold) <= members.len()
+            && members.iter().enumerate().all(|(i, signer)| {
+                *signer != Pubkey::default() && !members.iter().take(i).any(|s| s == signer)
+            })
+            && self
+                .signers
+                .iter()
+                .skip(members.len())
+                .all(|signer| *signer == Pubkey::default())
+    }
+
+    /// Count the members of this set found among the transaction signers.
+    pub fn count_approvals(&self, tx_signers: &[Pubkey]) -> usize {
+        self.members()
+            .iter()
+            .filter(|signer| tx_signers.contains(signer))
+            .count()
+    }
+
+    pub fn is_approved(&self, tx_signers: &[Pubkey]) -> bool {
+        self.count_approvals(tx_signers) >= usize::from(self.threshold)
+    }
+}
 
 pub trait Ownable {
     fn owner(&self) -> &Pubkey;
@@ -8,20 +93,330 @@ pub trait Ownable {
     fn owner_mut(&mut self) -> &mut Pubkey;
 }
 
+/// Number of slots (roughly one week) a pending ownership transfer request can be confirmed in.
+pub const PENDING_OWNER_EXPIRY_SLOTS: u64 = 1_512_000;
+
+/// Number of slots (roughly one day) the previous owner assistant remains authorized after the
+/// owner assistant is rotated.
+pub const OWNER_ASSISTANT_ROTATION_SLOTS: u64 = 216_000;
+
+/// Slot window of a pending ownership transfer request.
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct PendingOwnerWindow {
+    pub requested_slot: u64,
+    pub expiry_slot: u64,
+}
+
+impl PendingOwnerWindow {
+    pub fn new(current_slot: u64) -> Self {
+        Self {
+            requested_slot: current_slot,
+            expiry_slot: current_slot.saturating_add(PENDING_OWNER_EXPIRY_SLOTS),
+        }
+    }
+
+    pub fn is_expired(&self, current_slot: u64) -> bool {
+        current_slot >= self.expiry_slot
+    }
+}
+
+/// The previous owner assistant, which overlaps with the current owner assistant until the
+/// rotation is completed or expires.
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct OwnerAssistantRotation {
+    pub previous_owner_assistant: Pubkey,
+    pub expiry_slot: u64,
+}
+
+impl OwnerAssistantRotation {
+    pub fn new(previous_owner_assistant: Pubkey, current_slot: u64) -> Self {
+        Self {
+            previous_owner_assistant,
+            expiry_slot: current_slot.saturating_add(OWNER_ASSISTANT_ROTATION_SLOTS),
+        }
+    }
+
+    pub fn is_previous_owner_assistant(&self, key: &Pubkey, current_slot: u64) -> bool {
+        self.previous_owner_assistant == *key && current_slot < self.expiry_slot
+    }
+}
+
 pub trait PendingOwner: Ownable {
     fn pending_owner(&self) -> &Option<Pubkey>;
 
     fn pending_owner_mut(&mut self) -> &mut Option<Pubkey>;
+
+    fn pending_owner_window(&self) -> &PendingOwnerWindow;
+
+    fn pending_owner_window_mut(&mut self) -> &mut PendingOwnerWindow;
 }
 
 pub trait OwnerAssistant: Ownable {
     fn owner_assistant(&self) -> &Pubkey;
 
     fn owner_assistant_mut(&mut self) -> &mut Pubkey;
+
+    fn owner_assistant_rotation(&self) -> &Option<OwnerAssistantRotation>;
+
+    fn owner_assistant_rotation_mut(&mut self) -> &mut Option<OwnerAssistantRotation>;
+}
+
+pub trait MultisigOwnable: PendingOwner {
+    fn owner_set(&self) -> &OwnerSet;
+
+    fn owner_set_mut(&mut self) -> &mut OwnerSet;
+
+    fn pending_owner_set(&self) -> &Option<OwnerSet>;
+
+    fn pending_owner_set_mut(&mut self) -> &mut Option<OwnerSet>;
+}
+
+/// Maps failed admin checks to a program's own error codes. Required by the composites declared
+/// with [admin_composites](crate::admin_composites).
+pub trait AdminError {
+    fn owner_only() -> Error;
+
+    fn owner_or_assistant_only() -> Error;
+
+    fn missing_role() -> Error;
+}
+
+/// Maximum number of members in a program's role registry.
+pub const MAX_ROLE_MEMBERS: usize = 16;
+
+/// Roles the owner can grant to keys other than the owner and owner assistant. Each role only
+/// authorizes a specific group of admin instructions.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Role {
+    /// Can pause and unpause instructions.
+    Pauser,
+    /// Can propose router endpoint changes.
+    EndpointManager,
+    /// Can propose fee recipient and fee changes.
+    FeeManager,
+    /// Can propose auction parameter and proposal delay changes.
+    ParameterProposer,
+}
+
+impl Role {
+    pub fn mask(self) -> u8 {
+        match self {
+            Role::Pauser => 1,
+            Role::EndpointManager => 1 << 1,
+            Role::FeeManager => 1 << 2,
+            Role::ParameterProposer => 1 << 3,
+        }
+    }
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct RoleMember {
+    pub key: Pubkey,
+
+    /// Bitmask of granted roles. See [Role::mask].
+    pub roles: u8,
+}
+
+pub trait HasRoles {
+    fn role_members(&self) -> &Vec<RoleMember>;
+
+    fn role_members_mut(&mut self) -> &mut Vec<RoleMember>;
+
+    fn is_role_member(&self, key: &Pubkey) -> bool {
+        self.role_members().iter().any(|member| member.key == *key)
+    }
+
+    fn has_role(&self, key: &Pubkey, role: Role) -> bool {
+        self.role_members()
+            .iter()
+            .any(|member| member.key == *key && member.roles & role.mask() != 0)
+    }
+
+    /// Grant a role to a key. Returns false if the key is not a member yet and the registry is
+    /// full.
+    fn grant_role(&mut self, key: &Pubkey, role: Role) -> bool {
+        let members = self.role_members_mut();
+        let num_members = members.len();
+
+        match members.iter_mut().find(|member| member.key == *key) {
+            Some(member) => {
+                member.roles |= role.mask();
+                true
+            }
+            None if num_members < MAX_ROLE_MEMBERS => {
+                members.push(RoleMember {
+                    key: *key,
+                    roles: role.mask(),
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Revoke a role from a key. A member without any remaining roles is removed.
+    fn revoke_role(&mut self, key: &Pubkey, role: Role) {
+        let members = self.role_members_mut();
+
+        if let Some(member) = members.iter_mut().find(|member| member.key == *key) {
+            member.roles &= !role.mask();
+        }
+        members.retain(|member| member.roles != 0);
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    //    use super::*;
-    // TODO
+    #![allow(clippy::arithmetic_side_effects)]
+
+    use super::*;
+
+    #[test]
+    fn inactive_owner_set() {
+        let owner_set = OwnerSet::default();
+        assert!(owner_set.is_valid());
+        assert!(owner_set.is_approved(&[]));
+
+        let owner_set = OwnerSet::new(0, &[Pubkey::new_unique()]).unwrap();
+        assert!(!owner_set.is_valid());
+    }
+
+    #[test]
+    fn invalid_owner_sets() {
+        let signer = Pubkey::new_unique();
+
+        // Threshold exceeds number of signers.
+        assert!(!OwnerSet::new(2, &[signer]).unwrap().is_valid());
+
+        // Duplicate signer.
+        assert!(!OwnerSet::new(1, &[signer, signer]).unwrap().is_valid());
+
+        // Zero pubkey.
+        assert!(!OwnerSet::new(1, &[signer, Pubkey::default()])
+            .unwrap()
+            .is_valid());
+
+        // Too many signers.
+        let signers = (0..=MAX_OWNER_SET_SIGNERS)
+            .map(|_| Pubkey::new_unique())
+            .collect::<Vec<_>>();
+        assert!(OwnerSet::new(1, &signers).is_none());
+        assert!(!OwnerSet {
+            threshold: 1,
+            num_signers: u8::try_from(signers.len()).unwrap(),
+            signers: Default::default(),
+        }
+        .is_valid());
+
+        // Signer in an unused slot.
+        let mut owner_set = OwnerSet::new(1, &[signer]).unwrap();
+        owner_set.signers[1] = Pubkey::new_unique();
+        assert!(!owner_set.is_valid());
+        assert!(!owner_set.is_member(&owner_set.signers[1]));
+    }
+
+    #[test]
+    fn threshold_approvals() {
+        let signers = vec![
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let owner_set = OwnerSet::new(2, &signers).unwrap();
+        assert!(owner_set.is_valid());
+        assert_eq!(owner_set.members(), &signers[..]);
+
+        let outsider = Pubkey::new_unique();
+        assert!(!owner_set.is_approved(&[signers[0], outsider]));
+        assert!(!owner_set.is_approved(&[signers[0], signers[0]]));
+        assert!(owner_set.is_approved(&[outsider, signers[2], signers[0]]));
+    }
+
+    #[test]
+    fn owner_set_size_is_fixed() {
+        let empty = OwnerSet::default().try_to_vec().unwrap();
+        let full = OwnerSet::new(1, &[Pubkey::new_unique(); MAX_OWNER_SET_SIGNERS])
+            .unwrap()
+            .try_to_vec()
+            .unwrap();
+        assert_eq!(empty.len(), OwnerSet::INIT_SPACE);
+        assert_eq!(full.len(), OwnerSet::INIT_SPACE);
+    }
+
+    #[test]
+    fn pending_owner_window_expires() {
+        let window = PendingOwnerWindow::new(69);
+        assert!(!window.is_expired(69));
+        assert!(!window.is_expired(69 + PENDING_OWNER_EXPIRY_SLOTS - 1));
+        assert!(window.is_expired(69 + PENDING_OWNER_EXPIRY_SLOTS));
+
+        // Requests made before the window was introduced are expired.
+        assert!(PendingOwnerWindow::default().is_expired(0));
+    }
+
+    #[test]
+    fn owner_assistant_rotation_overlap() {
+        let previous = Pubkey::new_unique();
+        let rotation = OwnerAssistantRotation::new(previous, 69);
+
+        assert!(rotation.is_previous_owner_assistant(&previous, 69));
+        assert!(!rotation.is_previous_owner_assistant(&Pubkey::new_unique(), 69));
+        assert!(
+            !rotation.is_previous_owner_assistant(&previous, 69 + OWNER_ASSISTANT_ROTATION_SLOTS)
+        );
+    }
+
+    #[derive(Default)]
+    struct Registry {
+        members: Vec<RoleMember>,
+    }
+
+    impl HasRoles for Registry {
+        fn role_members(&self) -> &Vec<RoleMember> {
+            &self.members
+        }
+
+        fn role_members_mut(&mut self) -> &mut Vec<RoleMember> {
+            &mut self.members
+        }
+    }
+
+    #[test]
+    fn grant_and_revoke_roles() {
+        let mut registry = Registry::default();
+        let pauser = Pubkey::new_unique();
+
+        assert!(registry.grant_role(&pauser, Role::Pauser));
+        assert!(registry.grant_role(&pauser, Role::FeeManager));
+        assert!(registry.has_role(&pauser, Role::Pauser));
+        assert!(registry.has_role(&pauser, Role::FeeManager));
+        assert!(!registry.has_role(&pauser, Role::EndpointManager));
+        assert!(!registry.has_role(&Pubkey::new_unique(), Role::Pauser));
+
+        registry.revoke_role(&pauser, Role::FeeManager);
+        assert!(registry.has_role(&pauser, Role::Pauser));
+        assert!(!registry.has_role(&pauser, Role::FeeManager));
+
+        registry.revoke_role(&pauser, Role::Pauser);
+        assert!(!registry.is_role_member(&pauser));
+        assert!(registry.members.is_empty());
+    }
+
+    #[test]
+    fn role_registry_full() {
+        let mut registry = Registry::default();
+        for _ in 0..MAX_ROLE_MEMBERS {
+            assert!(registry.grant_role(&Pubkey::new_unique(), Role::Pauser));
+        }
+
+        assert!(!registry.grant_role(&Pubkey::new_unique(), Role::Pauser));
+
+        // Existing members can still be granted additional roles.
+        let member = registry.members[0].key;
+        assert!(registry.grant_role(&member, Role::ParameterProposer));
+    }
 }
@@ -1,6 +1,23 @@
-use crate::admin::OwnerAssistant;
+use super::multisig::require_owner_set_approval;
+use crate::admin::{MultisigOwnable, OwnerAssistant, OwnerAssistantRotation};
 use anchor_lang::prelude::*;
 
+/// Check whether the key is the owner assistant or the previous owner assistant, whose rotation
+/// window has not expired yet.
+pub fn is_owner_assistant<A>(acct: &Account<A>, key: &Pubkey) -> Result<bool>
+where
+    A: OwnerAssistant + Clone + AccountSerialize + AccountDeserialize,
+{
+    if acct.owner_assistant() == key {
+        return Ok(true);
+    }
+
+    match acct.owner_assistant_rotation() {
+        Some(rotation) => Ok(rotation.is_previous_owner_assistant(key, Clock::get()?.slot)),
+        None => Ok(false),
+    }
+}
+
 pub fn only_owner_assistant<A>(
     acct: &Account<A>,
     owner_assistant: &Signer,
@@ -9,33 +26,67 @@ pub fn only_owner_assistant<A>(
 where
     A: OwnerAssistant + Clone + AccountSerialize + AccountDeserialize,
 {
-    if acct.owner_assistant() == &owner_assistant.key() {
+    if is_owner_assistant(acct, &owner_assistant.key())? {
         Ok(true)
     } else {
         Err(custom_error.with_pubkeys((*acct.owner_assistant(), owner_assistant.key())))
     }
 }
 
+/// The owner assistant is authorized on its own. The owner (or a member of the owner set) must also
+/// have the owner set's approval if it is active (see [require_owner_set_approval]).
 pub fn only_authorized<A>(
     acct: &Account<A>,
     owner_or_assistant: &Signer,
+    instructions_sysvar: Option<&AccountInfo>,
     custom_error: Error,
 ) -> Result<bool>
 where
-    A: OwnerAssistant + Clone + AccountSerialize + AccountDeserialize,
+    A: OwnerAssistant + MultisigOwnable + Clone + AccountSerialize + AccountDeserialize,
 {
-    if acct.owner() == &owner_or_assistant.key()
-        || acct.owner_assistant() == &owner_or_assistant.key()
-    {
+    if is_owner_assistant(acct, &owner_or_assistant.key())? {
         Ok(true)
     } else {
-        Err(custom_error)
+        require_owner_set_approval(
+            &**acct,
+            owner_or_assistant,
+            instructions_sysvar,
+            custom_error,
+        )
     }
 }
 
+/// Replace the owner assistant immediately. Any rotation in progress is discarded.
 pub fn transfer_owner_assistant<A>(acct: &mut Account<A>, new_assistant: &AccountInfo)
 where
     A: OwnerAssistant + Clone + AccountSerialize + AccountDeserialize,
 {
     *acct.owner_assistant_mut() = new_assistant.key();
+    *acct.owner_assistant_rotation_mut() = None;
+}
+
+/// First phase of an owner assistant rotation. The new assistant is authorized immediately, while
+/// the previous assistant remains authorized until the rotation window expires.
+pub fn rotate_owner_assistant<A>(
+    acct: &mut Account<A>,
+    new_assistant: &AccountInfo,
+    current_slot: u64,
+) where
+    A: OwnerAssistant + Clone + AccountSerialize + AccountDeserialize,
+{
+    let previous_owner_assistant = *acct.owner_assistant();
+    *acct.owner_assistant_mut() = new_assistant.key();
+    *acct.owner_assistant_rotation_mut() = Some(OwnerAssistantRotation::new(
+        previous_owner_assistant,
+        current_slot,
+    ));
+}
+
+/// Second phase of an owner assistant rotation, which revokes the previous assistant before the
+/// rotation window expires.
+pub fn complete_owner_assistant_rotation<A>(acct: &mut Account<A>) -> bool
+where
+    A: OwnerAssistant + Clone + AccountSerialize + AccountDeserialize,
+{
+    acct.owner_assistant_rotation_mut().take().is_some()
 }
@@ -0,0 +1,23 @@
+use super::migrate::VersionedAccount;
+use anchor_lang::prelude::*;
+
+/// Check that an account is owned by the program that defines `T`, has the size of the current
+/// schema version, deserializes as `T` and was written at the current version. Upgraded programs
+/// use this to detect schema mismatches with accounts written (or not yet migrated) by a previous
+/// implementation.
+pub fn check_account_layout<T>(acc_info: &AccountInfo, err: Error) -> Result<()>
+where
+    T: VersionedAccount,
+{
+    if acc_info.owner != &T::owner() || acc_info.data_len() != T::SIZE {
+        return Err(err);
+    }
+
+    let data = acc_info.try_borrow_data()?;
+    let account = T::try_deserialize(&mut &data[..])?;
+    if account.version() != T::VERSION {
+        return Err(err);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use anchor_lang::{error::ErrorCode, prelude::*, system_program, Discriminator};
+
+/// Reallocate an account owned by the executing program if it is smaller than the specified size.
+/// Any additional rent is paid by the payer and the new data is zero-initialized.
+pub fn realloc_if_needed<'info>(
+    acc_info: &AccountInfo<'info>,
+    size: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if acc_info.data_len() < size {
+        let lamports_needed = Rent::get()?
+            .minimum_balance(size)
+            .saturating_sub(acc_info.lamports());
+
+        if lamports_needed > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: payer.to_account_info(),
+                        to: acc_info.clone(),
+                    },
+                ),
+                lamports_needed,
+            )?;
+        }
+
+        acc_info.realloc(size, true)?;
+    }
+
+    Ok(())
+}
+
+/// Account whose schema is versioned. The version byte is the last field of the first versioned
+/// schema. Fields introduced by later versions must be appended after the version byte.
+///
+/// Accounts created before versioning was introduced do not have a version byte, so they are
+/// identified by their size instead (these accounts are allocated at their full size).
+///
+/// Bytes after an account's serialized fields may be stale (e.g. left over from a longer `Option`
+/// or enum variant). So before an account is upgraded, everything after the fields written at its
+/// version is zeroed, which is how fields introduced by later versions are read as zero.
+///
+/// NOTE: Only long-lived accounts are versioned. Accounts closed within an order's lifecycle (like
+/// auctions and prepared orders) are not migrated.
+pub trait VersionedAccount:
+    AccountSerialize + AccountDeserialize + AnchorDeserialize + Owner + Discriminator
+{
+    /// Current schema version.
+    const VERSION: u8;
+
+    /// Account size (including discriminator) at the current schema version.
+    const SIZE: usize;
+
+    /// Account size (including discriminator) before versioning was introduced. Accounts no larger
+    /// than this are version zero regardless of the bytes read for the version. Zero if this
+    /// account was introduced with versioning.
+    const UNVERSIONED_SIZE: usize;
+
+    fn version(&self) -> u8;
+
+    fn version_mut(&mut self) -> &mut u8;
+
+    /// Read only the fields written at the account's schema version from `buf` (which excludes the
+    /// discriminator) and return this version. Version zero accounts must be read using the layout
+    /// from before versioning was introduced.
+    ///
+    /// The default implementation only supports accounts whose first schema version is the
+    /// current one. Accounts with any other version must override it.
+    fn read_version(buf: &mut &[u8], unversioned: bool) -> Result<u8> {
+        if unversioned {
+            return Err(ErrorCode::AccountDidNotDeserialize.into());
+        }
+
+        let account = <Self as AnchorDeserialize>::deserialize(buf)?;
+        Ok(account.version())
+    }
+
+    /// Upgrade an account written at `version` to `version + 1`. Every field introduced by the
+    /// next version is read as zero, so it only needs to be set here if its default is not zero.
+    fn upgrade_from(&mut self, version: u8) -> Result<()>;
+}
+
+/// Upgrade account data written at any previous version to the current version. The data must
+/// already be at least [VersionedAccount::SIZE] bytes. Any bytes after the fields written at the
+/// previous version are zeroed before the account is deserialized at the current version, so they
+/// cannot be misread by fields introduced by later versions. Returns whether the account was
+/// upgraded.
+pub fn upgrade_account_data<T>(data: &mut [u8], unversioned: bool, err: Error) -> Result<bool>
+where
+    T: VersionedAccount,
+{
+    if data.get(..8) != Some(&T::DISCRIMINATOR[..]) {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+
+    let (version, written_len) = {
+        let mut buf = data.get(8..).unwrap_or_default();
+        let version = T::read_version(&mut buf, unversioned)?;
+        (version, data.len().saturating_sub(buf.len()))
+    };
+
+    match version.cmp(&T::VERSION) {
+        std::cmp::Ordering::Equal => return Ok(false),
+        std::cmp::Ordering::Greater => return Err(err),
+        std::cmp::Ordering::Less => (),
+    }
+
+    // Everything after the fields written at this version (including the version byte of an
+    // unversioned account) is zeroed.
+    if let Some(stale) = data.get_mut(written_len..) {
+        stale.fill(0);
+    }
+
+    let mut account = T::try_deserialize(&mut &data[..])?;
+    for from_version in version..T::VERSION {
+        account.upgrade_from(from_version)?;
+    }
+    *account.version_mut() = T::VERSION;
+
+    data.fill(0);
+    account.try_serialize(&mut &mut data[..])?;
+
+    Ok(true)
+}
+
+/// Reallocate an account owned by the executing program to its current size (if necessary) and
+/// upgrade its data to the current version. Returns whether the account was upgraded.
+pub fn migrate_account<'info, T>(
+    acc_info: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    err: Error,
+) -> Result<bool>
+where
+    T: VersionedAccount,
+{
+    if acc_info.owner != &T::owner() {
+        return Err(anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+
+    let unversioned = acc_info.data_len() <= T::UNVERSIONED_SIZE;
+    realloc_if_needed(acc_info, T::SIZE, payer, system_program)?;
+
+    let mut data = acc_info.try_borrow_mut_data()?;
+    upgrade_account_data::<T>(&mut data, unversioned, err)
+}
@@ -1,7 +1,15 @@
 pub mod assistant;
 
+pub mod health;
+
+pub mod migrate;
+
+pub mod multisig;
+
 pub mod ownable;
 
 pub mod pending_owner;
 
+pub mod roles;
+
 pub mod upgrade;
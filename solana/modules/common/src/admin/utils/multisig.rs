@@ -0,0 +1,97 @@
+use crate::admin::{MultisigOwnable, OwnerSet};
+use anchor_lang::prelude::*;
+use solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+/// Collect the signers of the currently executing top-level instruction. Additional owner set
+/// signers are expected to be passed in as remaining accounts of this instruction.
+pub fn transaction_signers(instructions_sysvar: &AccountInfo) -> Result<Vec<Pubkey>> {
+    let index = load_current_index_checked(instructions_sysvar)?;
+    let instruction = load_instruction_at_checked(index.into(), instructions_sysvar)?;
+
+    Ok(instruction
+        .accounts
+        .into_iter()
+        .filter_map(|meta| meta.is_signer.then_some(meta.pubkey))
+        .collect())
+}
+
+/// Check that the signer is either the owner or a member of the owner set. If the owner set is
+/// active, the transaction must also be signed by at least the threshold number of its members,
+/// which requires the instructions sysvar.
+pub fn only_owner_set<A>(
+    acct: &Account<A>,
+    owner: &Signer,
+    instructions_sysvar: Option<&AccountInfo>,
+    custom_error: Error,
+) -> Result<bool>
+where
+    A: MultisigOwnable + Clone + AccountSerialize + AccountDeserialize,
+{
+    require_owner_set_approval(&**acct, owner, instructions_sysvar, custom_error)
+}
+
+/// Same as [only_owner_set], but for account data that was not loaded as an [Account] (e.g. an
+/// account that had to be migrated before it could be deserialized).
+pub fn require_owner_set_approval<A>(
+    acct: &A,
+    owner: &Signer,
+    instructions_sysvar: Option<&AccountInfo>,
+    custom_error: Error,
+) -> Result<bool>
+where
+    A: MultisigOwnable,
+{
+    let owner_set = acct.owner_set();
+
+    if !owner_set.is_active() {
+        return if acct.owner() == &owner.key() {
+            Ok(true)
+        } else {
+            Err(custom_error.with_pubkeys((*acct.owner(), owner.key())))
+        };
+    }
+
+    if acct.owner() != &owner.key() && !owner_set.is_member(&owner.key()) {
+        return Err(custom_error.with_pubkeys((*acct.owner(), owner.key())));
+    }
+
+    match instructions_sysvar {
+        Some(instructions_sysvar) => {
+            if owner_set.is_approved(&transaction_signers(instructions_sysvar)?) {
+                Ok(true)
+            } else {
+                Err(custom_error)
+            }
+        }
+        None => Err(custom_error),
+    }
+}
+
+pub fn transfer_owner_set<A>(acct: &mut Account<A>, new_owner_set: OwnerSet)
+where
+    A: MultisigOwnable + Clone + AccountSerialize + AccountDeserialize,
+{
+    acct.pending_owner_set_mut().replace(new_owner_set);
+}
+
+pub fn accept_owner_set<A>(acct: &mut Account<A>) -> bool
+where
+    A: MultisigOwnable + Clone + AccountSerialize + AccountDeserialize,
+{
+    match acct.pending_owner_set_mut().take() {
+        Some(owner_set) => {
+            *acct.owner_set_mut() = owner_set;
+            true
+        }
+        None => false,
+    }
+}
+
+pub fn cancel_transfer_owner_set<A>(acct: &mut Account<A>)
+where
+    A: MultisigOwnable + Clone + AccountSerialize + AccountDeserialize,
+{
+    *acct.pending_owner_set_mut() = None;
+}
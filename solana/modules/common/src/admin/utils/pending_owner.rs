@@ -1,4 +1,4 @@
-use crate::admin::PendingOwner;
+use crate::admin::{PendingOwner, PendingOwnerWindow};
 use anchor_lang::prelude::*;
 
 pub fn only_pending_owner_unchecked<A>(acct: &Account<A>, pending_owner: &Pubkey) -> bool
@@ -16,11 +16,20 @@ where
     pending.is_some() && only_pending_owner_unchecked(acct, pending_owner)
 }
 
-pub fn transfer_ownership<A>(acct: &mut Account<A>, new_owner: &Pubkey)
+/// Stale requests cannot be confirmed. See [PendingOwnerWindow].
+pub fn is_pending_owner_expired<A>(acct: &Account<A>, current_slot: u64) -> bool
+where
+    A: PendingOwner + Clone + AccountSerialize + AccountDeserialize,
+{
+    acct.pending_owner_window().is_expired(current_slot)
+}
+
+pub fn transfer_ownership<A>(acct: &mut Account<A>, new_owner: &Pubkey, current_slot: u64)
 where
     A: PendingOwner + Clone + AccountSerialize + AccountDeserialize,
 {
     acct.pending_owner_mut().replace(*new_owner);
+    *acct.pending_owner_window_mut() = PendingOwnerWindow::new(current_slot);
 }
 
 pub fn accept_ownership_unchecked<A>(acct: &mut Account<A>)
@@ -29,6 +38,7 @@ where
 {
     *acct.owner_mut() = *acct.pending_owner().as_ref().unwrap();
     *acct.pending_owner_mut() = None;
+    *acct.pending_owner_window_mut() = Default::default();
 }
 
 pub fn accept_ownership<A>(acct: &mut Account<A>) -> bool
@@ -48,4 +58,5 @@ where
     A: PendingOwner + Clone + AccountSerialize + AccountDeserialize,
 {
     *acct.pending_owner_mut() = None;
+    *acct.pending_owner_window_mut() = Default::default();
 }
@@ -0,0 +1,29 @@
+use super::multisig::require_owner_set_approval;
+use crate::admin::{HasRoles, MultisigOwnable, OwnerAssistant, Role};
+use anchor_lang::prelude::*;
+
+/// The owner and owner assistant are authorized for every role. Any other signer must be granted
+/// the specific role in the program's role registry. The owner (or a member of the owner set) must
+/// also have the owner set's approval if it is active (see [require_owner_set_approval]).
+pub fn only_role<A, R>(
+    acct: &Account<A>,
+    roles: Option<&Account<R>>,
+    signer: &Signer,
+    instructions_sysvar: Option<&AccountInfo>,
+    role: Role,
+    custom_error: Error,
+) -> Result<bool>
+where
+    A: OwnerAssistant + MultisigOwnable + Clone + AccountSerialize + AccountDeserialize,
+    R: HasRoles + Clone + AccountSerialize + AccountDeserialize,
+{
+    let key = signer.key();
+
+    if super::assistant::is_owner_assistant(acct, &key)?
+        || roles.is_some_and(|roles| roles.has_role(&key, role))
+    {
+        Ok(true)
+    } else {
+        require_owner_set_approval(&**acct, signer, instructions_sysvar, custom_error)
+    }
+}
@@ -0,0 +1,294 @@
+//! Helpers for pinning the serialized layout of account schemas. A program describes a canonical
+//! instance of each account field by field and compares it against a committed snapshot, so any
+//! change to field order, field sizes or the discriminator is caught before it reaches accounts
+//! already written on-chain.
+//!
+//! Snapshots are plain text with one line per field (including the discriminator):
+//!
+//! ```text
+//! <name> <offset> <len> <hex>
+//! ```
+//!
+//! Lines starting with `#` are ignored.
+
+use anchor_lang::{prelude::*, Discriminator};
+use std::fmt::Write;
+
+const DISCRIMINATOR_FIELD: &str = "discriminator";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldLayout {
+    pub name: String,
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountLayout {
+    fields: Vec<FieldLayout>,
+    len: usize,
+}
+
+impl AccountLayout {
+    /// Start a layout with the account's discriminator.
+    pub fn new<T: Discriminator>() -> Self {
+        Self {
+            fields: vec![FieldLayout {
+                name: DISCRIMINATOR_FIELD.to_string(),
+                offset: 0,
+                bytes: T::DISCRIMINATOR.to_vec(),
+            }],
+            len: T::DISCRIMINATOR.len(),
+        }
+    }
+
+    /// Append the next field of the account. Fields must be added in declaration order.
+    pub fn field<V: AnchorSerialize>(mut self, name: &str, value: &V) -> Self {
+        let bytes = value.try_to_vec().unwrap_or_default();
+        let offset = self.len;
+        self.len = offset.saturating_add(bytes.len());
+        self.fields.push(FieldLayout {
+            name: name.to_string(),
+            offset,
+            bytes,
+        });
+        self
+    }
+
+    pub fn fields(&self) -> &[FieldLayout] {
+        &self.fields
+    }
+
+    /// Render this layout in the snapshot format.
+    pub fn to_snapshot(&self) -> String {
+        let mut snapshot = String::new();
+        for field in &self.fields {
+            let _ = writeln!(
+                snapshot,
+                "{} {} {} {}",
+                field.name,
+                field.offset,
+                field.bytes.len(),
+                encode_hex(&field.bytes)
+            );
+        }
+        snapshot
+    }
+
+    /// Verify that the fields describe the account's actual serialization and that they match the
+    /// committed snapshot. The error lists every field that was added, removed, moved, resized or
+    /// re-encoded, followed by the snapshot reflecting the current layout.
+    pub fn check<T: AccountSerialize>(
+        &self,
+        account: &T,
+        snapshot: &str,
+    ) -> std::result::Result<(), String> {
+        self.check_account(account)?;
+
+        let expected = parse_snapshot(snapshot)?;
+        let mut diffs = Vec::new();
+
+        for old in &expected {
+            match self.fields.iter().find(|field| field.name == old.name) {
+                None => diffs.push(format!(
+                    "`{}` was removed (was at offset {})",
+                    old.name, old.offset
+                )),
+                Some(new) => {
+                    if new.offset != old.offset {
+                        diffs.push(format!(
+                            "`{}` moved from offset {} to {}",
+                            old.name, old.offset, new.offset
+                        ));
+                    }
+                    if new.bytes.len() != old.bytes.len() {
+                        diffs.push(format!(
+                            "`{}` changed size from {} to {} bytes",
+                            old.name,
+                            old.bytes.len(),
+                            new.bytes.len()
+                        ));
+                    } else if new.bytes != old.bytes {
+                        diffs.push(format!(
+                            "`{}` changed encoding from {} to {}",
+                            old.name,
+                            encode_hex(&old.bytes),
+                            encode_hex(&new.bytes)
+                        ));
+                    }
+                }
+            }
+        }
+
+        for new in &self.fields {
+            if !expected.iter().any(|old| old.name == new.name) {
+                diffs.push(format!("`{}` was added at offset {}", new.name, new.offset));
+            }
+        }
+
+        if diffs.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "account layout changed:\n  {}\n\ncurrent layout:\n{}",
+                diffs.join("\n  "),
+                self.to_snapshot()
+            ))
+        }
+    }
+
+    fn check_account<T: AccountSerialize>(&self, account: &T) -> std::result::Result<(), String> {
+        let mut data = Vec::with_capacity(self.len);
+        account
+            .try_serialize(&mut data)
+            .map_err(|err| format!("failed to serialize account: {err}"))?;
+
+        for field in &self.fields {
+            let end = field.offset.saturating_add(field.bytes.len());
+            if data.get(field.offset..end) != Some(field.bytes.as_slice()) {
+                return Err(format!(
+                    "`{}` does not match the account's serialization at offset {}; are the fields \
+                     listed in declaration order?",
+                    field.name, field.offset
+                ));
+            }
+        }
+
+        if data.len() != self.len {
+            return Err(format!(
+                "account serializes to {} bytes but the listed fields cover {}",
+                data.len(),
+                self.len
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_snapshot(snapshot: &str) -> std::result::Result<Vec<FieldLayout>, String> {
+    snapshot
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let parts = line.split_whitespace().collect::<Vec<_>>();
+            match parts.as_slice() {
+                [name, offset, len, hex] => {
+                    let offset = offset
+                        .parse()
+                        .map_err(|_| format!("invalid offset in snapshot line: {line}"))?;
+                    let bytes = decode_hex(hex)
+                        .ok_or_else(|| format!("invalid bytes in snapshot line: {line}"))?;
+                    if len.parse::<usize>().ok() != Some(bytes.len()) {
+                        return Err(format!("invalid length in snapshot line: {line}"));
+                    }
+                    Ok(FieldLayout {
+                        name: name.to_string(),
+                        offset,
+                        bytes,
+                    })
+                }
+                _ => Err(format!("malformed snapshot line: {line}")),
+            }
+        })
+        .collect()
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len().saturating_mul(2));
+    for byte in bytes {
+        let _ = write!(hex, "{byte:02x}");
+    }
+    hex
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    let pairs = hex.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+
+    pairs
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(AnchorSerialize)]
+    struct Dummy {
+        a: u8,
+        b: Option<u16>,
+        c: Vec<u8>,
+    }
+
+    impl Discriminator for Dummy {
+        const DISCRIMINATOR: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+    }
+
+    impl AccountSerialize for Dummy {
+        fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+            writer.write_all(&Self::DISCRIMINATOR)?;
+            AnchorSerialize::serialize(self, writer)?;
+            Ok(())
+        }
+    }
+
+    fn dummy_layout(dummy: &Dummy) -> AccountLayout {
+        AccountLayout::new::<Dummy>()
+            .field("a", &dummy.a)
+            .field("b", &dummy.b)
+            .field("c", &dummy.c)
+    }
+
+    fn dummy() -> Dummy {
+        Dummy {
+            a: 1,
+            b: Some(2),
+            c: vec![3],
+        }
+    }
+
+    #[test]
+    fn snapshot_round_trip() {
+        let dummy = dummy();
+        let layout = dummy_layout(&dummy);
+        assert_eq!(layout.check(&dummy, &layout.to_snapshot()), Ok(()));
+    }
+
+    #[test]
+    fn reports_moved_and_resized_fields() {
+        let dummy = dummy();
+        let layout = dummy_layout(&dummy);
+        let mut snapshot = layout.to_snapshot();
+
+        let moved = Dummy { b: None, ..dummy };
+        let moved_layout = dummy_layout(&moved);
+        let err = moved_layout.check(&moved, &snapshot).unwrap_err();
+        assert!(err.contains("`b` changed size from 3 to 1 bytes"));
+        assert!(err.contains("`c` moved from offset 12 to 10"));
+        assert!(!err.contains("`a`"));
+
+        snapshot.push_str("d 17 1 00\n");
+        let err = layout.check(&self::dummy(), &snapshot).unwrap_err();
+        assert!(err.contains("`d` was removed (was at offset 17)"));
+    }
+
+    #[test]
+    fn rejects_fields_out_of_order() {
+        let dummy = dummy();
+        let layout = AccountLayout::new::<Dummy>()
+            .field("b", &dummy.b)
+            .field("a", &dummy.a)
+            .field("c", &dummy.c);
+        let err = layout.check(&dummy, &layout.to_snapshot()).unwrap_err();
+        assert!(err.starts_with("`b` does not match"));
+    }
+}
@@ -2,6 +2,9 @@ pub use wormhole_cctp_solana;
 
 pub mod admin;
 
+#[cfg(any(test, feature = "testing"))]
+pub mod layout;
+
 pub use liquidity_layer_messages as messages;
 pub use messages::wormhole_io;
 
@@ -12,6 +15,11 @@ pub const CCTP_MESSAGE_SEED_PREFIX: &[u8] = b"cctp-msg";
 
 pub const TRANSFER_AUTHORITY_SEED_PREFIX: &[u8] = b"transfer-authority";
 
+/// Seed prefix of the Token Router's custody token account holding the funds of a fast market order
+/// placed from Solana, keyed by the sequence of its finalized Wormhole message. The Matching Engine custodian
+/// is the owner of this token account.
+pub const LOCAL_ORDER_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"local-order-custody";
+
 pub use wormhole_solana_consts::USDC_MINT;
 
 use solana_program::{pubkey, pubkey::Pubkey};
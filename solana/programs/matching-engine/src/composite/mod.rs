@@ -4,14 +4,14 @@ use crate::{
     error::MatchingEngineError,
     state::{
         Auction, AuctionStatus, Custodian, FastFillSequencer, MessageProtocol,
-        PreparedOrderResponse, ReservedFastFillSequence, RouterEndpoint,
+        PreparedOrderResponse, Proposal, ProposalAction, ReservedFastFillSequence, RoleRegistry,
+        RouterEndpoint,
     },
     utils::{self, VaaDigest},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use common::{
-    admin::utils::{assistant::only_authorized, ownable::only_owner},
     messages::raw::LiquidityLayerMessage,
     wormhole_cctp_solana::{
         cctp::{message_transmitter_program, token_messenger_minter_program},
@@ -19,20 +19,7 @@ use common::{
     },
 };
 
-#[derive(Accounts)]
-pub struct Usdc<'info> {
-    /// CHECK: This address must equal [USDC_MINT](common::USDC_MINT).
-    #[account(address = common::USDC_MINT)]
-    pub mint: UncheckedAccount<'info>,
-}
-
-impl<'info> Deref for Usdc<'info> {
-    type Target = UncheckedAccount<'info>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.mint
-    }
-}
+common::admin_composites!(Custodian, RoleRegistry, MatchingEngineError);
 
 /// Mint recipient token account, which is encoded as the mint recipient in the CCTP message.
 /// The CCTP Token Messenger Minter program will transfer the amount encoded in the CCTP message
@@ -91,87 +78,120 @@ impl<'info> Deref for LiquidityLayerVaa<'info> {
     }
 }
 
-#[derive(Accounts)]
-pub struct CheckedCustodian<'info> {
-    #[account(
-        seeds = [Custodian::SEED_PREFIX],
-        bump = Custodian::BUMP,
-    )]
-    pub custodian: Box<Account<'info, Custodian>>,
-}
-
-impl<'info> Deref for CheckedCustodian<'info> {
-    type Target = Account<'info, Custodian>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.custodian
-    }
-}
-
-#[derive(Accounts)]
-pub struct OwnerOnly<'info> {
-    #[account(
-        constraint = only_owner(
-            &custodian,
-            &owner,
-            error!(MatchingEngineError::OwnerOnly)
-        )?
-    )]
-    pub owner: Signer<'info>,
+/// Accounts borrowed from a new proposal composite to create the proposal.
+pub struct NewProposalParts<'a, 'info> {
+    pub by: Pubkey,
+    pub custodian: &'a mut Account<'info, Custodian>,
+    pub proposal: &'a mut Account<'info, Proposal>,
+    pub epoch_schedule: &'a EpochSchedule,
+}
+
+/// Declares a composite for creating a proposal, whose proposer is authorized by the `$admin`
+/// composite.
+macro_rules! new_proposal_composite {
+    ($name:ident, $admin:ident, $proposer:ident) => {
+        #[derive(Accounts)]
+        pub struct $name<'info> {
+            #[account(mut)]
+            pub payer: Signer<'info>,
+
+            pub admin: $admin<'info>,
+
+            #[account(
+                init,
+                payer = payer,
+                space = 8 + Proposal::INIT_SPACE,
+                seeds = [
+                    Proposal::SEED_PREFIX,
+                    &admin.custodian.next_proposal_id.to_be_bytes()
+                ],
+                bump,
+            )]
+            pub proposal: Box<Account<'info, Proposal>>,
+
+            pub system_program: Program<'info, System>,
+
+            pub epoch_schedule: Sysvar<'info, EpochSchedule>,
+        }
 
-    pub custodian: CheckedCustodian<'info>,
-}
+        impl<'info> $name<'info> {
+            pub fn parts(&mut self) -> NewProposalParts<'_, 'info> {
+                NewProposalParts {
+                    by: self.admin.$proposer.key(),
+                    custodian: &mut self.admin.custodian,
+                    proposal: &mut self.proposal,
+                    epoch_schedule: &self.epoch_schedule,
+                }
+            }
+        }
+    };
+}
+
+new_proposal_composite!(NewProposal, AdminMut, owner_or_assistant);
+new_proposal_composite!(
+    NewEndpointManagerProposal,
+    EndpointManagerOnlyMut,
+    authority
+);
+new_proposal_composite!(NewFeeManagerProposal, FeeManagerOnlyMut, authority);
+new_proposal_composite!(
+    NewParameterProposerProposal,
+    ParameterProposerOnlyMut,
+    authority
+);
 
 #[derive(Accounts)]
-pub struct OwnerOnlyMut<'info> {
-    #[account(
-        constraint = only_owner(
-            &custodian,
-            &owner,
-            error!(MatchingEngineError::OwnerOnly)
-        )?
-    )]
-    pub owner: Signer<'info>,
+pub struct EnactProposal<'info> {
+    pub admin: OwnerOnlyMut<'info>,
 
     #[account(
         mut,
-        seeds = [Custodian::SEED_PREFIX],
-        bump = Custodian::BUMP,
-    )]
-    pub custodian: Box<Account<'info, Custodian>>,
-}
+        seeds = [
+            Proposal::SEED_PREFIX,
+            &proposal.id.to_be_bytes(),
+        ],
+        bump = proposal.bump,
+        constraint = {
+            // The owner only composite checks the owner set's approval, so any member of the owner
+            // set can enact a proposal made under the current owner.
+            require_keys_eq!(
+                proposal.owner, admin.custodian.owner
+            );
+            require!(
+                proposal.slot_enacted_at.is_none(),
+                MatchingEngineError::ProposalAlreadyEnacted
+            );
 
-#[derive(Accounts)]
-pub struct Admin<'info> {
-    #[account(
-        constraint = only_authorized(
-            &custodian,
-            &owner_or_assistant,
-            error!(MatchingEngineError::OwnerOrAssistantOnly)
-        )?
-    )]
-    pub owner_or_assistant: Signer<'info>,
+            let current_slot = Clock::get().unwrap().slot;
+            require!(
+                current_slot >= proposal.slot_enact_delay,
+                MatchingEngineError::ProposalDelayNotExpired
+            );
 
-    pub custodian: CheckedCustodian<'info>,
-}
+            let default_delay =
+                crate::utils::admin::default_proposal_delay(&EpochSchedule::get().unwrap());
+            require!(
+                current_slot
+                    < proposal.slot_enact_delay.saturating_add(
+                        admin.custodian.proposal_delays.expiry_window(default_delay)
+                    ),
+                MatchingEngineError::ProposalExpired
+            );
 
-#[derive(Accounts)]
-pub struct AdminMut<'info> {
-    #[account(
-        constraint = only_authorized(
-            &custodian,
-            &owner_or_assistant,
-            error!(MatchingEngineError::OwnerOrAssistantOnly)
-        )?
+            true
+        }
     )]
-    pub owner_or_assistant: Signer<'info>,
+    pub proposal: Box<Account<'info, Proposal>>,
+}
 
-    #[account(
-        mut,
-        seeds = [Custodian::SEED_PREFIX],
-        bump = Custodian::BUMP,
-    )]
-    pub custodian: Box<Account<'info, Custodian>>,
+impl<'info> EnactProposal<'info> {
+    /// Mark the proposal as enacted and return its action.
+    pub fn enact(&mut self) -> ProposalAction {
+        // Set the slot enacted at so it cannot be replayed.
+        self.proposal.slot_enacted_at = Clock::get().unwrap().slot.into();
+
+        self.proposal.action
+    }
 }
 
 #[derive(Accounts)]
@@ -288,6 +308,11 @@ pub struct FastOrderPath<'info> {
     )]
     pub fast_vaa: LiquidityLayerVaa<'info>,
 
+    #[account(
+        constraint = {
+            !path.from_endpoint.pause.paused && !path.to_endpoint.pause.paused
+        } @ MatchingEngineError::EndpointPaused,
+    )]
     pub path: LiveRouterPath<'info>,
 }
 
@@ -2,6 +2,8 @@
 pub enum MatchingEngineError {
     OwnerOnly = 0x2,
     OwnerOrAssistantOnly = 0x4,
+    MissingRole = 0x6,
+    RoleRegistryFull = 0x8,
 
     U64Overflow = 0x10,
     U32Overflow = 0x12,
@@ -23,8 +25,11 @@ pub enum MatchingEngineError {
     InvalidTargetRouter = 0x62,
     EndpointDisabled = 0x64,
     InvalidCctpEndpoint = 0x66,
+    InboundRateLimitExceeded = 0x68,
+    OutboundRateLimitExceeded = 0x6a,
 
     Paused = 0x80,
+    EndpointPaused = 0x82,
 
     AssistantZeroPubkey = 0x100,
     FeeRecipientZeroPubkey = 0x101,
@@ -41,12 +46,18 @@ pub enum MatchingEngineError {
     ZeroSecurityDepositBase = 0x10e,
     #[msg("Value exceeds 1000000")]
     SecurityDepositBpsTooLarge = 0x10f,
+    ZeroRateLimitWindow = 0x110,
+    InvalidOrderSizeLimits = 0x112,
 
     InvalidNewOwner = 0x202,
     AlreadyOwner = 0x204,
     NoTransferOwnershipRequest = 0x206,
     NotPendingOwner = 0x208,
     InvalidChain = 0x20c,
+    InvalidOwnerSet = 0x20e,
+    OwnerSetNotApproved = 0x210,
+    PendingOwnerExpired = 0x212,
+    NoOwnerAssistantRotation = 0x214,
 
     ChainNotAllowed = 0x240,
     InvalidMintRecipient = 0x242,
@@ -54,11 +65,14 @@ pub enum MatchingEngineError {
     ProposalAlreadyEnacted = 0x300,
     ProposalDelayNotExpired = 0x302,
     InvalidProposal = 0x304,
+    ProposalExpired = 0x306,
 
     AuctionConfigMismatch = 0x340,
 
     FastMarketOrderExpired = 0x400,
     OfferPriceTooHigh = 0x402,
+    AmountInTooSmall = 0x404,
+    FastAmountInTooLarge = 0x406,
     AuctionNotActive = 0x408,
     AuctionPeriodExpired = 0x40a,
     AuctionPeriodNotExpired = 0x40c,
@@ -83,10 +97,28 @@ pub enum MatchingEngineError {
     AuctionAlreadySettled = 0x43a,
     InvalidBaseFeeToken = 0x43c,
     BaseFeeTokenRequired = 0x43e,
+    MinAmountOutNotMet = 0x440,
 
     CannotCloseAuctionYet = 0x500,
     AuctionHistoryNotFull = 0x502,
     AuctionHistoryFull = 0x504,
+
+    AccountLayoutMismatch = 0x600,
+    UnsupportedAccountVersion = 0x602,
+}
+
+impl common::admin::AdminError for MatchingEngineError {
+    fn owner_only() -> anchor_lang::error::Error {
+        anchor_lang::error!(Self::OwnerOnly)
+    }
+
+    fn owner_or_assistant_only() -> anchor_lang::error::Error {
+        anchor_lang::error!(Self::OwnerOrAssistantOnly)
+    }
+
+    fn missing_role() -> anchor_lang::error::Error {
+        anchor_lang::error!(Self::MissingRole)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,8 @@
+use crate::state::ProposalAction;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct Cancelled {
+    pub id: u64,
+    pub action: ProposalAction,
+}
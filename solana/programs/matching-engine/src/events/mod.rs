@@ -7,6 +7,9 @@ pub use auction_settled::*;
 mod auction_updated;
 pub use auction_updated::*;
 
+mod cancelled;
+pub use cancelled::*;
+
 mod enacted;
 pub use enacted::*;
 
@@ -22,5 +25,20 @@ pub use filled_local_fast_order::*;
 mod order_executed;
 pub use order_executed::*;
 
+mod order_size_limits_updated;
+pub use order_size_limits_updated::*;
+
+mod pause_updated;
+pub use pause_updated::*;
+
 mod proposed;
 pub use proposed::*;
+
+mod rate_limits_updated;
+pub use rate_limits_updated::*;
+
+mod role_updated;
+pub use role_updated::*;
+
+mod router_endpoint_pause_updated;
+pub use router_endpoint_pause_updated::*;
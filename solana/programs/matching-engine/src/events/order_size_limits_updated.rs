@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct OrderSizeLimitsUpdated {
+    pub chain: u16,
+    pub min_amount_in: u64,
+    pub max_fast_amount_in: Option<u64>,
+}
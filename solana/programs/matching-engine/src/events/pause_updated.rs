@@ -0,0 +1,9 @@
+use crate::state::PauseGroup;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PauseUpdated {
+    pub group: PauseGroup,
+    pub paused: bool,
+    pub set_by: Pubkey,
+}
@@ -0,0 +1,9 @@
+use crate::state::RateLimitConfig;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct RateLimitsUpdated {
+    pub chain: u16,
+    pub inbound: Option<RateLimitConfig>,
+    pub outbound: Option<RateLimitConfig>,
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+use common::admin::Role;
+
+#[event]
+pub struct RoleUpdated {
+    pub member: Pubkey,
+    pub role: Role,
+    pub granted: bool,
+}
@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct RouterEndpointPauseUpdated {
+    pub chain: u16,
+    pub paused: bool,
+    pub set_by: Pubkey,
+}
@@ -61,20 +61,76 @@ pub mod matching_engine {
         processor::initialize(ctx, args)
     }
 
-    /// This instruction is used to pause or unpause further processing of new auctions. Only the
-    /// `owner` or `owner_assistant` can pause the program.
+    /// This instruction is used to pause or unpause a group of instructions (new auctions, offer
+    /// improvements, execution, local fast fills or settlement). Only the `owner`,
+    /// `owner_assistant` or a `Pauser` can pause the program.
     ///
     /// # Arguments
     ///
-    /// * `ctx`   - `SetPause` context.
-    /// * `pause` - Boolean indicating whether to pause the program.
-    pub fn set_pause(ctx: Context<SetPause>, pause: bool) -> Result<()> {
-        processor::set_pause(ctx, pause)
+    /// * `ctx`   - `SetGroupPause` context.
+    /// * `group` - Group of instructions to pause or unpause.
+    /// * `pause` - Boolean indicating whether to pause the group.
+    pub fn set_group_pause(
+        ctx: Context<SetGroupPause>,
+        group: state::PauseGroup,
+        pause: bool,
+    ) -> Result<()> {
+        processor::set_group_pause(ctx, group, pause)
     }
 
-    /// This instruction is used to add a new Token Router endpoint from a foreign chain. The
-    /// endpoint must be CCTP compatible. This instruction can only be called by the `owner` or
-    /// `owner_assistant`.
+    /// This instruction is used to pause or unpause fast orders to or from a router endpoint.
+    /// Auctions on other routes are unaffected. Only the `owner`, `owner_assistant` or a `Pauser`
+    /// can pause a router endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`   - `SetRouterEndpointPause` context.
+    /// * `chain` - Chain of the router endpoint.
+    /// * `pause` - Boolean indicating whether to pause the router endpoint.
+    pub fn set_router_endpoint_pause(
+        ctx: Context<SetRouterEndpointPause>,
+        chain: u16,
+        pause: bool,
+    ) -> Result<()> {
+        processor::set_router_endpoint_pause(ctx, chain, pause)
+    }
+
+    /// This instruction is used to grant a role to a key other than the `owner` and
+    /// `owner_assistant`. The role registry is created if it does not exist yet. This instruction
+    /// can only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`    - `UpdateRole` context.
+    /// * `member` - Key to grant the role to.
+    /// * `role`   - Role to grant.
+    pub fn grant_role(
+        ctx: Context<UpdateRole>,
+        member: Pubkey,
+        role: common::admin::Role,
+    ) -> Result<()> {
+        processor::grant_role(ctx, member, role)
+    }
+
+    /// This instruction is used to revoke a role. A key without any remaining roles is removed from
+    /// the role registry. This instruction can only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`    - `UpdateRole` context.
+    /// * `member` - Key to revoke the role from.
+    /// * `role`   - Role to revoke.
+    pub fn revoke_role(
+        ctx: Context<UpdateRole>,
+        member: Pubkey,
+        role: common::admin::Role,
+    ) -> Result<()> {
+        processor::revoke_role(ctx, member, role)
+    }
+
+    /// This instruction is used to enact an existing proposal to add a new Token Router endpoint
+    /// from a foreign chain. The endpoint must be CCTP compatible. It can only be executed after
+    /// the `slot_enact_delay` has passed. This instruction can only be called by the `owner`.
     ///
     /// # Arguments
     ///
@@ -87,9 +143,9 @@ pub mod matching_engine {
         processor::add_cctp_router_endpoint(ctx, args)
     }
 
-    /// This instruction is used to add a new Local Router endpoint. Local means that the
-    /// Token Router program exists on Solana. This instruction can only be called by the
-    /// `owner` or `owner_assistant`.
+    /// This instruction is used to enact an existing proposal to add a new Local Router endpoint.
+    /// Local means that the Token Router program exists on Solana. It can only be executed after
+    /// the `slot_enact_delay` has passed. This instruction can only be called by the `owner`.
     ///
     /// # Arguments
     ///
@@ -98,9 +154,10 @@ pub mod matching_engine {
         processor::add_local_router_endpoint(ctx)
     }
 
-    /// This instruction is used to disable a router endpoint. This instruction does not close the
-    /// account, it only sets the `protocol` to `None` and clears the `address` and
-    /// `mint_recipient`. This instruction can only be called by the `owner`.
+    /// This instruction is used to enact an existing proposal to disable a router endpoint. This
+    /// instruction does not close the account, it only sets the `protocol` to `None` and clears the
+    /// `address` and `mint_recipient`. It can only be executed after the `slot_enact_delay` has
+    /// passed. This instruction can only be called by the `owner`.
     ///
     /// # Arguments
     ///
@@ -109,8 +166,47 @@ pub mod matching_engine {
         processor::disable_router_endpoint(ctx)
     }
 
-    /// This instruction is used to update a CCTP router endpoint. It allows the caller to change
-    /// the `address`, `mint_recipient`, and `domain`. This instruction can only be called by the
+    /// This instruction is used to set the inbound and outbound rate limits for a router endpoint.
+    /// Fast orders that would exceed either limit within the rolling window cannot be auctioned
+    /// and must be settled via the slow (finalized) path instead. Setting a limit to `None`
+    /// removes it. This instruction can only be called by the `owner`.
+    ///
+    /// NOTE: Router endpoints without a rate limiter have no limits, so fast orders on existing
+    /// routes can be auctioned before this instruction is called for them.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `SetRouterEndpointRateLimits` context.
+    /// * `args` - The `SetRateLimitsArgs`, see `rate_limits.rs`.
+    pub fn set_router_endpoint_rate_limits(
+        ctx: Context<SetRouterEndpointRateLimits>,
+        args: SetRateLimitsArgs,
+    ) -> Result<()> {
+        processor::set_router_endpoint_rate_limits(ctx, args)
+    }
+
+    /// This instruction is used to set the minimum and maximum order sizes allowed to be auctioned
+    /// for a router endpoint. Fast orders below the minimum are rejected for auction, and fast
+    /// orders above the maximum can only be settled via the slow (finalized) path. This
+    /// instruction can only be called by the `owner`.
+    ///
+    /// NOTE: Router endpoints created before order size limits were introduced must be upgraded via
+    /// `migrate_accounts` before this instruction is called for them.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `SetRouterEndpointOrderSizeLimits` context.
+    /// * `args` - The `SetOrderSizeLimitsArgs`, see `order_size_limits.rs`.
+    pub fn set_router_endpoint_order_size_limits(
+        ctx: Context<SetRouterEndpointOrderSizeLimits>,
+        args: SetOrderSizeLimitsArgs,
+    ) -> Result<()> {
+        processor::set_router_endpoint_order_size_limits(ctx, args)
+    }
+
+    /// This instruction is used to enact an existing proposal to update a CCTP router endpoint. It
+    /// allows the caller to change the `address`, `mint_recipient`, and `domain`. It can only be
+    /// executed after the `slot_enact_delay` has passed. This instruction can only be called by the
     /// `owner`.
     ///
     /// # Arguments
@@ -124,8 +220,9 @@ pub mod matching_engine {
         processor::update_cctp_router_endpoint(ctx, args)
     }
 
-    /// This instruction is used to update a Local router endpoint. It allows the caller to change
-    /// the `address` and `mint_recipient`. This instruction can only be called by the `owner`.
+    /// This instruction is used to enact an existing proposal to update a Local router endpoint. It
+    /// allows the caller to change the `address` and `mint_recipient`. It can only be executed
+    /// after the `slot_enact_delay` has passed. This instruction can only be called by the `owner`.
     ///
     /// # Arguments
     ///
@@ -149,7 +246,8 @@ pub mod matching_engine {
 
     /// This instruction confirms the ownership transfer request and sets the new `owner` in the
     /// `Custodian` account. This instruction can only be called by the `pending_owner`. The
-    /// `pending_owner` must be the same as the `pending_owner` in the `Custodian` account.
+    /// `pending_owner` must be the same as the `pending_owner` in the `Custodian` account, and the
+    /// request must be confirmed within `PENDING_OWNER_EXPIRY_SLOTS` of being submitted.
     ///
     /// # Arguments
     ///
@@ -172,9 +270,40 @@ pub mod matching_engine {
         processor::cancel_ownership_transfer_request(ctx)
     }
 
+    /// This instruction sets the `pending_owner_set` field in the `Custodian` account. This
+    /// instruction can only be called by the `owner` (or enough members of the active owner set).
+    /// An owner set with a zero threshold deactivates multisig ownership.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`       - `SubmitOwnerSetTransferRequest` context.
+    /// * `owner_set` - The new M-of-N `OwnerSet`.
+    pub fn submit_owner_set_transfer_request(
+        ctx: Context<SubmitOwnerSetTransferRequest>,
+        owner_set: common::admin::OwnerSet,
+    ) -> Result<()> {
+        processor::submit_owner_set_transfer_request(ctx, owner_set)
+    }
+
+    /// This instruction confirms the owner set transfer request and replaces the `owner_set` in
+    /// the `Custodian` account. The transaction must be signed by enough members of both the
+    /// current and the pending owner set. These signers are passed in as remaining accounts.
+    /// Cancelling an ownership transfer request also cancels a pending owner set.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ConfirmOwnerSetTransferRequest` context.
+    pub fn confirm_owner_set_transfer_request(
+        ctx: Context<ConfirmOwnerSetTransferRequest>,
+    ) -> Result<()> {
+        processor::confirm_owner_set_transfer_request(ctx)
+    }
+
     /// This instruction is used to propose new auction parameters. A proposal cannot be enacted
-    /// until one epoch has passed. This instruction can only be called by the `owner` or
-    /// `owner_assistant`.
+    /// until its configured delay (one epoch by default) has passed, and it expires if it is not
+    /// enacted within the configured expiry window after that. Multiple proposals can be pending at
+    /// the same time. This instruction can only be called by the `owner`, `owner_assistant` or a
+    /// `ParameterProposer`.
     ///
     /// # Arguments
     ///
@@ -187,6 +316,107 @@ pub mod matching_engine {
         processor::propose_auction_parameters(ctx, params)
     }
 
+    /// This instruction is used to propose adding a new CCTP router endpoint. This instruction can
+    /// only be called by the `owner`, `owner_assistant` or an `EndpointManager`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `ProposeCctpRouterEndpoint` context.
+    /// * `args` - The `AddCctpRouterEndpointArgs`, see `admin.rs`.
+    pub fn propose_add_cctp_router_endpoint(
+        ctx: Context<ProposeCctpRouterEndpoint>,
+        args: AddCctpRouterEndpointArgs,
+    ) -> Result<()> {
+        processor::propose_add_cctp_router_endpoint(ctx, args)
+    }
+
+    /// This instruction is used to propose updating an existing CCTP router endpoint. This
+    /// instruction can only be called by the `owner`, `owner_assistant` or an `EndpointManager`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `ProposeCctpRouterEndpoint` context.
+    /// * `args` - The `AddCctpRouterEndpointArgs`, see `admin.rs`.
+    pub fn propose_update_cctp_router_endpoint(
+        ctx: Context<ProposeCctpRouterEndpoint>,
+        args: AddCctpRouterEndpointArgs,
+    ) -> Result<()> {
+        processor::propose_update_cctp_router_endpoint(ctx, args)
+    }
+
+    /// This instruction is used to propose adding a new Local router endpoint. This instruction
+    /// can only be called by the `owner`, `owner_assistant` or an `EndpointManager`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ProposeLocalRouterEndpoint` context.
+    pub fn propose_add_local_router_endpoint(
+        ctx: Context<ProposeLocalRouterEndpoint>,
+    ) -> Result<()> {
+        processor::propose_add_local_router_endpoint(ctx)
+    }
+
+    /// This instruction is used to propose updating the existing Local router endpoint. This
+    /// instruction can only be called by the `owner`, `owner_assistant` or an `EndpointManager`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ProposeLocalRouterEndpoint` context.
+    pub fn propose_update_local_router_endpoint(
+        ctx: Context<ProposeLocalRouterEndpoint>,
+    ) -> Result<()> {
+        processor::propose_update_local_router_endpoint(ctx)
+    }
+
+    /// This instruction is used to propose disabling a router endpoint. This instruction can only
+    /// be called by the `owner`, `owner_assistant` or an `EndpointManager`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ProposeDisableRouterEndpoint` context.
+    pub fn propose_disable_router_endpoint(
+        ctx: Context<ProposeDisableRouterEndpoint>,
+    ) -> Result<()> {
+        processor::propose_disable_router_endpoint(ctx)
+    }
+
+    /// This instruction is used to propose a new fee recipient. This instruction can only be called
+    /// by the `owner`, `owner_assistant` or a `FeeManager`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ProposeFeeRecipient` context.
+    pub fn propose_fee_recipient(ctx: Context<ProposeFeeRecipient>) -> Result<()> {
+        processor::propose_fee_recipient(ctx)
+    }
+
+    /// This instruction is used to propose a new owner assistant. This instruction can only be
+    /// called by the `owner` or `owner_assistant`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ProposeOwnerAssistant` context.
+    pub fn propose_owner_assistant(ctx: Context<ProposeOwnerAssistant>) -> Result<()> {
+        processor::propose_owner_assistant(ctx)
+    }
+
+    /// This instruction is used to propose new enact delays for each proposal action and the
+    /// window in which a proposal can be enacted before it expires. A zero delay means the default
+    /// delay is used. This proposal's own delay is the longest configured delay.
+    /// This instruction can only be called by the `owner`, `owner_assistant` or a
+    /// `ParameterProposer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`    - `ProposeProposalDelays` context.
+    /// * `delays` - The new `ProposalDelays`, see `proposal.rs`.
+    pub fn propose_proposal_delays(
+        ctx: Context<ProposeProposalDelays>,
+        delays: state::ProposalDelays,
+    ) -> Result<()> {
+        processor::propose_proposal_delays(ctx, delays)
+    }
+
     /// This instruction is used to enact an existing auction update proposal. It can only be
     /// executed after the `slot_enact_delay` has passed. This instruction can only be called by the
     /// `owner`.
@@ -198,8 +428,29 @@ pub mod matching_engine {
         processor::update_auction_parameters(ctx)
     }
 
+    /// This instruction is used to enact an existing proposal delays update proposal. It can only
+    /// be executed after the `slot_enact_delay` has passed. This instruction can only be called by
+    /// the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `UpdateProposalDelays` context.
+    pub fn update_proposal_delays(ctx: Context<UpdateProposalDelays>) -> Result<()> {
+        processor::update_proposal_delays(ctx)
+    }
+
+    /// This instruction is used to cancel a pending proposal by closing the proposal account. Other
+    /// pending proposals are unaffected. This instruction can only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `CancelProposal` context.
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        processor::cancel_proposal(ctx)
+    }
+
     /// This instruction is used to close an existing proposal by closing the proposal account. This
-    /// instruction can only be called by the `owner` or `owner_assistant`.
+    /// instruction can only be called by the `owner`, `owner_assistant` or the proposal's author.
     ///
     /// # Arguments
     ///
@@ -208,8 +459,11 @@ pub mod matching_engine {
         processor::close_proposal(ctx)
     }
 
-    /// This instruction is used to update the `owner_assistant` field in the `Custodian` account.
-    /// This instruction can only be called by the `owner`.
+    /// This instruction is used to enact an existing proposal to update the `owner_assistant`
+    /// field in the `Custodian` account. It can only be executed after the `slot_enact_delay` has
+    /// passed. The previous assistant remains authorized for `OWNER_ASSISTANT_ROTATION_SLOTS`
+    /// unless the rotation is completed earlier. This instruction can only be called by the
+    /// `owner`.
     ///
     /// # Arguments
     ///
@@ -218,8 +472,22 @@ pub mod matching_engine {
         processor::update_owner_assistant(ctx)
     }
 
-    /// This instruction is used to update the `fee_recipient` field in the `Custodian` account.
-    /// This instruction can only be called by the `owner` or `owner_assistant`.
+    /// This instruction completes an owner assistant rotation by revoking the previous
+    /// `owner_assistant` before its rotation window expires. This instruction can only be called
+    /// by the `owner` or `owner_assistant`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `CompleteOwnerAssistantRotation` context.
+    pub fn complete_owner_assistant_rotation(
+        ctx: Context<CompleteOwnerAssistantRotation>,
+    ) -> Result<()> {
+        processor::complete_owner_assistant_rotation(ctx)
+    }
+
+    /// This instruction is used to enact an existing proposal to update the `fee_recipient` field
+    /// in the `Custodian` account. It can only be executed after the `slot_enact_delay` has passed.
+    /// This instruction can only be called by the `owner`.
     ///
     /// # Arguments
     ///
@@ -229,7 +497,8 @@ pub mod matching_engine {
     }
 
     /// This instruction is used for executing logic during an upgrade. This instruction can only be
-    /// called by the `upgrade_manager_program`.
+    /// called by the `upgrade_manager_program`. If the `Custodian` account is smaller than its
+    /// current size, it will be reallocated with the payer covering the additional rent.
     ///
     /// # Arguments
     ///
@@ -238,6 +507,32 @@ pub mod matching_engine {
         processor::migrate(ctx)
     }
 
+    /// This instruction is used to verify that the `Custodian` account (and any other versioned
+    /// accounts passed in as remaining accounts) have the expected size, can be deserialized and
+    /// were migrated to the current schema version. The `upgrade_manager_program` invokes this
+    /// instruction before committing an upgrade, forwarding the accounts passed to the commit.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `HealthCheck` context.
+    pub fn health_check(ctx: Context<HealthCheck>) -> Result<()> {
+        processor::health_check(ctx)
+    }
+
+    /// This instruction is used to upgrade versioned accounts (`Custodian`, `AuctionConfig`,
+    /// `RouterEndpoint`, `RateLimiter` and `RoleRegistry`) to their current schema version. The
+    /// accounts are passed in as remaining accounts and are reallocated if necessary, with the
+    /// payer covering the additional rent. Anyone can call this instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `MigrateAccounts` context.
+    pub fn migrate_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateAccounts<'info>>,
+    ) -> Result<()> {
+        processor::migrate_accounts(ctx)
+    }
+
     /// This instruction is used to create a new auction given a valid `FastMarketOrder` vaa. This
     /// instruction will record information about the auction and transfer funds from the payer to
     /// an auction-specific token custody account. This instruction can be called by anyone.
@@ -318,6 +613,21 @@ pub mod matching_engine {
         processor::prepare_order_response_cctp(ctx, args)
     }
 
+    /// This instruction is used to prepare the order response for a fast market order placed by
+    /// the Solana Token Router. Instead of redeeming a CCTP transfer, the funds deposited by the
+    /// Token Router when it placed the order are moved to the `prepared_custody_token` account. The
+    /// finalized VAA is the finalized copy of the fast market order published alongside it, which
+    /// must reconcile with the fast VAA.
+    /// This instruction will create a `PreparedOrderResponse` account that will be used to settle
+    /// the auction.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `PrepareOrderResponseLocal` context.
+    pub fn prepare_order_response_local(ctx: Context<PrepareOrderResponseLocal>) -> Result<()> {
+        processor::prepare_order_response_local(ctx)
+    }
+
     /// This instruction is used to settle the acution after the `FastMarketOrder` has been
     /// executed, and the `PreparedOrderResponse` has been created. This instruction will settle the
     /// auction by transferring the funds from the `prepared_custody_token` account to the best
@@ -473,6 +783,20 @@ pub mod matching_engine {
     pub fn add_auction_history_entry(_ctx: Context<DeprecatedInstruction>) -> Result<()> {
         err!(ErrorCode::Deprecated)
     }
+
+    /// DEPRECATED. This instruction does not exist anymore. See `set_group_pause` and
+    /// `set_router_endpoint_pause`.
+    ///
+    /// This instruction is used to pause or unpause further processing of new auctions. Only the
+    /// `owner` or `owner_assistant` can pause the program.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`   - `SetPause` context.
+    /// * `pause` - Boolean indicating whether to pause the program.
+    pub fn set_pause(_ctx: Context<DeprecatedInstruction>, _pause: bool) -> Result<()> {
+        err!(ErrorCode::Deprecated)
+    }
 }
 
 #[derive(Accounts)]
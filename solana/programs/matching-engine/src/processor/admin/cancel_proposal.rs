@@ -0,0 +1,39 @@
+use crate::{composite::*, error::MatchingEngineError, state::Proposal};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CancelProposal<'info> {
+    admin: OwnerOnly<'info>,
+
+    /// CHECK: This account must equal proposal.by pubkey.
+    #[account(
+        mut,
+        address = proposal.by
+    )]
+    proposed_by: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = proposed_by,
+        seeds = [
+            Proposal::SEED_PREFIX,
+            &proposal.id.to_be_bytes(),
+        ],
+        bump = proposal.bump,
+        constraint = proposal.slot_enacted_at.is_none() @ MatchingEngineError::ProposalAlreadyEnacted
+    )]
+    proposal: Account<'info, Proposal>,
+}
+
+pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+
+    emit_cpi!(crate::events::Cancelled {
+        id: proposal.id,
+        action: proposal.action,
+    });
+
+    // Done.
+    Ok(())
+}
@@ -1,9 +1,32 @@
 use crate::{composite::*, error::MatchingEngineError, state::Proposal};
 use anchor_lang::prelude::*;
+use common::admin::utils::assistant::only_authorized;
 
 #[derive(Accounts)]
 pub struct CloseProposal<'info> {
-    admin: Admin<'info>,
+    /// Owner, owner assistant or the proposer. Role members can only close their own proposals.
+    #[account(
+        constraint = {
+            proposal.by == closer.key()
+                || only_authorized(
+                    &custodian,
+                    &closer,
+                    instructions_sysvar.as_deref(),
+                    error!(MatchingEngineError::OwnerOrAssistantOnly)
+                )?
+        }
+    )]
+    closer: Signer<'info>,
+
+    /// Required if the owner set is active and the closer is the owner or a member of the owner
+    /// set. The transaction must be signed by enough members of the owner set, which are passed in
+    /// as remaining accounts.
+    ///
+    /// CHECK: Must equal instructions sysvar ID.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    custodian: CheckedCustodian<'info>,
 
     /// CHECK: This account must equal proposal.by pubkey.
     #[account(
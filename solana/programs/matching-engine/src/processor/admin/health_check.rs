@@ -0,0 +1,48 @@
+use crate::{
+    error::MatchingEngineError,
+    state::{AuctionConfig, Custodian, RateLimiter, RoleRegistry, RouterEndpoint},
+};
+use anchor_lang::{prelude::*, Discriminator};
+use common::admin::utils::health::check_account_layout;
+
+#[derive(Accounts)]
+pub struct HealthCheck<'info> {
+    /// CHECK: Seeds must be \["emitter"\]. This account is deserialized in the health check.
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+    )]
+    custodian: UncheckedAccount<'info>,
+}
+
+pub fn health_check(ctx: Context<HealthCheck>) -> Result<()> {
+    check_account_layout::<Custodian>(
+        &ctx.accounts.custodian,
+        error!(MatchingEngineError::AccountLayoutMismatch),
+    )?;
+
+    // Any other versioned accounts can be checked by passing them in as remaining accounts.
+    for acc_info in ctx.remaining_accounts {
+        let discriminator = acc_info
+            .try_borrow_data()?
+            .get(..8)
+            .and_then(|discriminator| <[u8; 8]>::try_from(discriminator).ok())
+            .ok_or_else(|| error!(MatchingEngineError::AccountLayoutMismatch))?;
+        let err = error!(MatchingEngineError::AccountLayoutMismatch);
+
+        if discriminator == AuctionConfig::DISCRIMINATOR {
+            check_account_layout::<AuctionConfig>(acc_info, err)
+        } else if discriminator == RouterEndpoint::DISCRIMINATOR {
+            check_account_layout::<RouterEndpoint>(acc_info, err)
+        } else if discriminator == RateLimiter::DISCRIMINATOR {
+            check_account_layout::<RateLimiter>(acc_info, err)
+        } else if discriminator == RoleRegistry::DISCRIMINATOR {
+            check_account_layout::<RoleRegistry>(acc_info, err)
+        } else {
+            Err(err)
+        }?;
+    }
+
+    // Done.
+    Ok(())
+}
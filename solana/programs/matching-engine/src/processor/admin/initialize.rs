@@ -1,10 +1,11 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{AuctionConfig, Custodian},
+    state::{AuctionConfig, Custodian, PauseFlag},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
+use common::admin::utils::migrate::VersionedAccount;
 use wormhole_solana_utils::cpi::bpf_loader_upgradeable::{self, BpfLoaderUpgradeable};
 
 // Because this is used as the args for initialize, we'll make it public here.
@@ -147,17 +148,30 @@ pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()>
     ctx.accounts.custodian.set_inner(Custodian {
         owner,
         pending_owner: None,
-        paused: false,
-        paused_set_by: owner,
+        new_auctions_pause: PauseFlag {
+            paused: false,
+            set_by: owner,
+        },
         owner_assistant: ctx.accounts.owner_assistant.key(),
         fee_recipient_token: ctx.accounts.fee_recipient_token.key(),
         auction_config_id,
         next_proposal_id: Default::default(),
+        offer_improvements_pause: Default::default(),
+        execution_pause: Default::default(),
+        local_fast_fills_pause: Default::default(),
+        settlement_pause: Default::default(),
+        proposal_delays: Default::default(),
+        owner_set: Default::default(),
+        pending_owner_set: None,
+        pending_owner_window: Default::default(),
+        owner_assistant_rotation: None,
+        version: Custodian::VERSION,
     });
 
     ctx.accounts.auction_config.set_inner(AuctionConfig {
         id: auction_config_id,
         parameters: auction_params,
+        version: AuctionConfig::VERSION,
     });
 
     // Done.
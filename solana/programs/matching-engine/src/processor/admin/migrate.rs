@@ -1,13 +1,56 @@
-use crate::composite::*;
+use crate::{error::MatchingEngineError, state::Custodian};
 use anchor_lang::prelude::*;
+use common::admin::utils::{migrate::migrate_account, multisig::require_owner_set_approval};
 
 #[derive(Accounts)]
 pub struct Migrate<'info> {
-    admin: OwnerOnly<'info>,
+    /// Pays for any additional rent required to reallocate the custodian.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    owner: Signer<'info>,
+
+    /// Required if the owner set is active. The transaction must be signed by enough members of
+    /// the owner set, which are passed in as remaining accounts.
+    ///
+    /// CHECK: Must equal instructions sysvar ID.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// Custodians created before granular pause controls were introduced are smaller than the
+    /// current account size, so this account is reallocated (if necessary) and upgraded to the
+    /// current version before it is deserialized.
+    ///
+    /// CHECK: Mutable. Seeds must be \["emitter"\].
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+    )]
+    custodian: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
 }
 
-pub fn migrate(_ctx: Context<Migrate>) -> Result<()> {
-    msg!("Nothing to migrate");
+pub fn migrate(ctx: Context<Migrate>) -> Result<()> {
+    let custodian = &ctx.accounts.custodian;
+    migrate_account::<Custodian>(
+        custodian,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        error!(MatchingEngineError::UnsupportedAccountVersion),
+    )?;
+
+    // The custodian is deserialized directly because it could not be loaded by the accounts
+    // struct before it was upgraded.
+    let custodian = Custodian::try_deserialize(&mut &custodian.try_borrow_data()?[..])?;
+    require_owner_set_approval(
+        &custodian,
+        &ctx.accounts.owner,
+        ctx.accounts.instructions_sysvar.as_deref(),
+        error!(MatchingEngineError::OwnerOnly),
+    )?;
 
+    // Done.
     Ok(())
 }
@@ -0,0 +1,53 @@
+use crate::{
+    error::MatchingEngineError,
+    state::{AuctionConfig, Custodian, RateLimiter, RoleRegistry, RouterEndpoint},
+};
+use anchor_lang::{prelude::*, Discriminator};
+use common::admin::utils::migrate::migrate_account;
+
+#[derive(Accounts)]
+pub struct MigrateAccounts<'info> {
+    /// Pays for any additional rent required to reallocate the accounts being migrated.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn migrate_accounts<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateAccounts<'info>>,
+) -> Result<()> {
+    let payer = &ctx.accounts.payer;
+    let system_program = &ctx.accounts.system_program;
+
+    // Accounts to migrate are passed in as remaining accounts. Each must be writable.
+    for acc_info in ctx.remaining_accounts {
+        let discriminator = acc_info
+            .try_borrow_data()?
+            .get(..8)
+            .and_then(|discriminator| <[u8; 8]>::try_from(discriminator).ok())
+            .ok_or_else(|| error!(MatchingEngineError::AccountLayoutMismatch))?;
+        let err = error!(MatchingEngineError::UnsupportedAccountVersion);
+
+        let migrated = if discriminator == Custodian::DISCRIMINATOR {
+            migrate_account::<Custodian>(acc_info, payer, system_program, err)
+        } else if discriminator == AuctionConfig::DISCRIMINATOR {
+            migrate_account::<AuctionConfig>(acc_info, payer, system_program, err)
+        } else if discriminator == RouterEndpoint::DISCRIMINATOR {
+            migrate_account::<RouterEndpoint>(acc_info, payer, system_program, err)
+        } else if discriminator == RateLimiter::DISCRIMINATOR {
+            migrate_account::<RateLimiter>(acc_info, payer, system_program, err)
+        } else if discriminator == RoleRegistry::DISCRIMINATOR {
+            migrate_account::<RoleRegistry>(acc_info, payer, system_program, err)
+        } else {
+            err!(MatchingEngineError::AccountLayoutMismatch)
+        }?;
+
+        if migrated {
+            msg!("Migrated {}", acc_info.key());
+        }
+    }
+
+    // Done.
+    Ok(())
+}
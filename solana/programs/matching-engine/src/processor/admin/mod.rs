@@ -1,6 +1,12 @@
+mod cancel_proposal;
+pub use cancel_proposal::*;
+
 mod close_proposal;
 pub use close_proposal::*;
 
+mod health_check;
+pub use health_check::*;
+
 mod initialize;
 pub use initialize::*;
 
@@ -10,12 +16,18 @@ pub use set_pause::*;
 mod migrate;
 pub use migrate::*;
 
+mod migrate_accounts;
+pub use migrate_accounts::*;
+
 mod ownership_transfer_request;
 pub use ownership_transfer_request::*;
 
 mod propose;
 pub use propose::*;
 
+mod role;
+pub use role::*;
+
 mod router_endpoint;
 pub use router_endpoint::*;
 
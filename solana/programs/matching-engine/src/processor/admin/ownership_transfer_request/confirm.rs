@@ -18,6 +18,9 @@ pub struct ConfirmOwnershipTransferRequest<'info> {
         constraint = {
             pending_owner::only_pending_owner_unchecked(&custodian, &pending_owner.key())
         } @ MatchingEngineError::NotPendingOwner,
+        constraint = {
+            !pending_owner::is_pending_owner_expired(&custodian, Clock::get()?.slot)
+        } @ MatchingEngineError::PendingOwnerExpired,
     )]
     custodian: Account<'info, Custodian>,
 }
@@ -0,0 +1,42 @@
+use crate::{composite::*, error::MatchingEngineError};
+use anchor_lang::prelude::*;
+use common::admin::utils::multisig;
+
+#[derive(Accounts)]
+pub struct ConfirmOwnerSetTransferRequest<'info> {
+    #[account(
+        constraint = {
+            admin.custodian.pending_owner_set.is_some()
+        } @ MatchingEngineError::NoTransferOwnershipRequest,
+    )]
+    admin: OwnerOnlyMut<'info>,
+}
+
+pub fn confirm_owner_set_transfer_request(
+    ctx: Context<ConfirmOwnerSetTransferRequest>,
+) -> Result<()> {
+    let admin = &mut ctx.accounts.admin;
+
+    // The new owner set must prove that enough of its members can sign before it replaces the
+    // current one.
+    if let Some(pending_owner_set) = admin
+        .custodian
+        .pending_owner_set
+        .as_ref()
+        .filter(|owner_set| owner_set.is_active())
+    {
+        let tx_signers = match admin.instructions_sysvar.as_ref() {
+            Some(instructions_sysvar) => multisig::transaction_signers(instructions_sysvar)?,
+            None => return err!(MatchingEngineError::OwnerSetNotApproved),
+        };
+        require!(
+            pending_owner_set.is_approved(&tx_signers),
+            MatchingEngineError::OwnerSetNotApproved
+        );
+    }
+
+    multisig::accept_owner_set(&mut admin.custodian);
+
+    // Done.
+    Ok(())
+}
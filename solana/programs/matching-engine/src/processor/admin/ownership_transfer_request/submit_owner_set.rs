@@ -0,0 +1,23 @@
+use crate::{composite::*, error::MatchingEngineError};
+use anchor_lang::prelude::*;
+use common::admin::OwnerSet;
+
+#[derive(Accounts)]
+pub struct SubmitOwnerSetTransferRequest<'info> {
+    admin: OwnerOnlyMut<'info>,
+}
+
+pub fn submit_owner_set_transfer_request(
+    ctx: Context<SubmitOwnerSetTransferRequest>,
+    owner_set: OwnerSet,
+) -> Result<()> {
+    require!(owner_set.is_valid(), MatchingEngineError::InvalidOwnerSet);
+
+    common::admin::utils::multisig::transfer_owner_set(
+        &mut ctx.accounts.admin.custodian,
+        owner_set,
+    );
+
+    // Done.
+    Ok(())
+}
@@ -1,33 +1,14 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{AuctionParameters, Proposal, ProposalAction},
+    state::{AuctionParameters, ProposalAction},
 };
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 #[event_cpi]
 pub struct ProposeAuctionParameters<'info> {
-    #[account(mut)]
-    payer: Signer<'info>,
-
-    admin: Admin<'info>,
-
-    #[account(
-        init,
-        payer = payer,
-        space = 8 + Proposal::INIT_SPACE,
-        seeds = [
-            Proposal::SEED_PREFIX,
-            &admin.custodian.next_proposal_id.to_be_bytes()
-        ],
-        bump,
-    )]
-    proposal: Account<'info, Proposal>,
-
-    system_program: Program<'info, System>,
-
-    epoch_schedule: Sysvar<'info, EpochSchedule>,
+    new_proposal: NewParameterProposerProposal<'info>,
 }
 
 pub fn propose_auction_parameters(
@@ -38,6 +19,7 @@ pub fn propose_auction_parameters(
 
     let id = ctx
         .accounts
+        .new_proposal
         .admin
         .custodian
         .auction_config_id
@@ -46,14 +28,9 @@ pub fn propose_auction_parameters(
     let action = ProposalAction::UpdateAuctionParameters { id, parameters };
 
     super::propose(
-        super::Propose {
-            custodian: &ctx.accounts.admin.custodian,
-            proposal: &mut ctx.accounts.proposal,
-            by: &ctx.accounts.admin.owner_or_assistant,
-            epoch_schedule: &ctx.accounts.epoch_schedule,
-        },
+        ctx.accounts.new_proposal.parts(),
         action,
-        ctx.bumps.proposal,
+        ctx.bumps.new_proposal.proposal,
     )?;
 
     // Emit event reflecting the proposal.
@@ -0,0 +1,42 @@
+use crate::{composite::*, error::MatchingEngineError, state::ProposalAction};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeFeeRecipient<'info> {
+    new_proposal: NewFeeManagerProposal<'info>,
+
+    #[account(
+        associated_token::mint = common::USDC_MINT,
+        associated_token::authority = new_fee_recipient,
+    )]
+    new_fee_recipient_token: Account<'info, token::TokenAccount>,
+
+    /// New Fee Recipient.
+    ///
+    /// CHECK: Must not be zero pubkey.
+    #[account(
+        constraint = {
+            new_fee_recipient.key() != Pubkey::default()
+        } @ MatchingEngineError::FeeRecipientZeroPubkey,
+    )]
+    new_fee_recipient: UncheckedAccount<'info>,
+}
+
+pub fn propose_fee_recipient(ctx: Context<ProposeFeeRecipient>) -> Result<()> {
+    let action = ProposalAction::UpdateFeeRecipient {
+        fee_recipient_token: ctx.accounts.new_fee_recipient_token.key(),
+    };
+    super::propose(
+        ctx.accounts.new_proposal.parts(),
+        action,
+        ctx.bumps.new_proposal.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
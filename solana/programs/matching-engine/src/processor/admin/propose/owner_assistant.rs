@@ -0,0 +1,35 @@
+use crate::{composite::*, error::MatchingEngineError, state::ProposalAction};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeOwnerAssistant<'info> {
+    new_proposal: NewProposal<'info>,
+
+    /// New Assistant.
+    ///
+    /// CHECK: Must not be zero pubkey.
+    #[account(
+        constraint = {
+            new_owner_assistant.key() != Pubkey::default()
+        } @ MatchingEngineError::AssistantZeroPubkey,
+    )]
+    new_owner_assistant: UncheckedAccount<'info>,
+}
+
+pub fn propose_owner_assistant(ctx: Context<ProposeOwnerAssistant>) -> Result<()> {
+    let action = ProposalAction::UpdateOwnerAssistant {
+        owner_assistant: ctx.accounts.new_owner_assistant.key(),
+    };
+    super::propose(
+        ctx.accounts.new_proposal.parts(),
+        action,
+        ctx.bumps.new_proposal.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
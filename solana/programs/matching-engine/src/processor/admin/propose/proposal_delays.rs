@@ -0,0 +1,29 @@
+use crate::{
+    composite::*,
+    state::{ProposalAction, ProposalDelays},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeProposalDelays<'info> {
+    new_proposal: NewParameterProposerProposal<'info>,
+}
+
+pub fn propose_proposal_delays(
+    ctx: Context<ProposeProposalDelays>,
+    delays: ProposalDelays,
+) -> Result<()> {
+    let action = ProposalAction::UpdateProposalDelays { delays };
+    super::propose(
+        ctx.accounts.new_proposal.parts(),
+        action,
+        ctx.bumps.new_proposal.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::{composite::*, state::ProposalAction, utils, AddCctpRouterEndpointArgs};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeCctpRouterEndpoint<'info> {
+    new_proposal: NewEndpointManagerProposal<'info>,
+}
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeLocalRouterEndpoint<'info> {
+    new_proposal: NewEndpointManagerProposal<'info>,
+
+    local: LocalTokenRouter<'info>,
+}
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeDisableRouterEndpoint<'info> {
+    new_proposal: NewEndpointManagerProposal<'info>,
+
+    router_endpoint: LiveRouterEndpoint<'info>,
+}
+
+pub fn propose_add_cctp_router_endpoint(
+    ctx: Context<ProposeCctpRouterEndpoint>,
+    args: AddCctpRouterEndpointArgs,
+) -> Result<()> {
+    utils::admin::require_valid_cctp_router_endpoint_args(&args)?;
+
+    let action = ProposalAction::AddCctpRouterEndpoint { args };
+    super::propose(
+        ctx.accounts.new_proposal.parts(),
+        action,
+        ctx.bumps.new_proposal.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
+
+pub fn propose_update_cctp_router_endpoint(
+    ctx: Context<ProposeCctpRouterEndpoint>,
+    args: AddCctpRouterEndpointArgs,
+) -> Result<()> {
+    utils::admin::require_valid_cctp_router_endpoint_args(&args)?;
+
+    let action = ProposalAction::UpdateCctpRouterEndpoint { args };
+    super::propose(
+        ctx.accounts.new_proposal.parts(),
+        action,
+        ctx.bumps.new_proposal.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
+
+pub fn propose_add_local_router_endpoint(ctx: Context<ProposeLocalRouterEndpoint>) -> Result<()> {
+    let action = ProposalAction::AddLocalRouterEndpoint {
+        token_router_program: ctx.accounts.local.token_router_program.key(),
+    };
+    super::propose(
+        ctx.accounts.new_proposal.parts(),
+        action,
+        ctx.bumps.new_proposal.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
+
+pub fn propose_update_local_router_endpoint(
+    ctx: Context<ProposeLocalRouterEndpoint>,
+) -> Result<()> {
+    let action = ProposalAction::UpdateLocalRouterEndpoint {
+        token_router_program: ctx.accounts.local.token_router_program.key(),
+    };
+    super::propose(
+        ctx.accounts.new_proposal.parts(),
+        action,
+        ctx.bumps.new_proposal.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
+
+pub fn propose_disable_router_endpoint(ctx: Context<ProposeDisableRouterEndpoint>) -> Result<()> {
+    let action = ProposalAction::DisableRouterEndpoint {
+        chain: ctx.accounts.router_endpoint.chain,
+    };
+    super::propose(
+        ctx.accounts.new_proposal.parts(),
+        action,
+        ctx.bumps.new_proposal.proposal,
+    )?;
+
+    // Emit event reflecting the proposal.
+    emit_cpi!(crate::events::Proposed { action });
+
+    // Done.
+    Ok(())
+}
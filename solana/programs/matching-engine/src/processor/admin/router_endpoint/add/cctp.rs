@@ -1,6 +1,7 @@
 use crate::{
     composite::*,
-    state::RouterEndpoint,
+    error::MatchingEngineError,
+    state::{ProposalAction, RouterEndpoint},
     utils::{self, admin::AddCctpRouterEndpointArgs},
 };
 use anchor_lang::prelude::*;
@@ -11,11 +12,17 @@ use common::wormhole_cctp_solana::cctp::token_messenger_minter_program::{
 
 #[derive(Accounts)]
 #[instruction(args: AddCctpRouterEndpointArgs)]
+#[event_cpi]
 pub struct AddCctpRouterEndpoint<'info> {
     #[account(mut)]
     payer: Signer<'info>,
 
-    admin: Admin<'info>,
+    #[account(
+        constraint = {
+            enact.proposal.action == ProposalAction::AddCctpRouterEndpoint { args }
+        } @ MatchingEngineError::InvalidProposal,
+    )]
+    enact: EnactProposal<'info>,
 
     #[account(
         init,
@@ -64,6 +71,11 @@ pub fn add_cctp_router_endpoint(
     ctx: Context<AddCctpRouterEndpoint>,
     args: AddCctpRouterEndpointArgs,
 ) -> Result<()> {
+    let action = ctx.accounts.enact.enact();
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
     utils::admin::handle_add_cctp_router_endpoint(
         &mut ctx.accounts.router_endpoint,
         args,
@@ -1,13 +1,19 @@
-use crate::{composite::*, state::RouterEndpoint, utils};
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{ProposalAction, RouterEndpoint},
+    utils,
+};
 use anchor_lang::prelude::*;
 use common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN;
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct AddLocalRouterEndpoint<'info> {
     #[account(mut)]
     payer: Signer<'info>,
 
-    admin: Admin<'info>,
+    enact: EnactProposal<'info>,
 
     #[account(
         init,
@@ -21,12 +27,24 @@ pub struct AddLocalRouterEndpoint<'info> {
     )]
     router_endpoint: Account<'info, RouterEndpoint>,
 
+    #[account(
+        constraint = {
+            enact.proposal.action == ProposalAction::AddLocalRouterEndpoint {
+                token_router_program: local.token_router_program.key(),
+            }
+        } @ MatchingEngineError::InvalidProposal,
+    )]
     local: LocalTokenRouter<'info>,
 
     system_program: Program<'info, System>,
 }
 
 pub fn add_local_router_endpoint(ctx: Context<AddLocalRouterEndpoint>) -> Result<()> {
+    let action = ctx.accounts.enact.enact();
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
     utils::admin::handle_add_local_router_endpoint(
         &mut ctx.accounts.router_endpoint,
         &ctx.accounts.local.token_router_program,
@@ -1,14 +1,31 @@
-use crate::{composite::*, state::MessageProtocol};
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{MessageProtocol, ProposalAction},
+};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct DisableRouterEndpoint<'info> {
-    admin: OwnerOnly<'info>,
+    enact: EnactProposal<'info>,
 
+    #[account(
+        constraint = {
+            enact.proposal.action == ProposalAction::DisableRouterEndpoint {
+                chain: router_endpoint.chain,
+            }
+        } @ MatchingEngineError::InvalidProposal,
+    )]
     router_endpoint: ExistingMutRouterEndpoint<'info>,
 }
 
 pub fn disable_router_endpoint(ctx: Context<DisableRouterEndpoint>) -> Result<()> {
+    let action = ctx.accounts.enact.enact();
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
     let endpoint = &mut ctx.accounts.router_endpoint.info;
     endpoint.protocol = MessageProtocol::None;
     endpoint.address = Default::default();
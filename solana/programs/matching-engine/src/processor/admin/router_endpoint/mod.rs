@@ -4,5 +4,14 @@ pub use add::*;
 mod disable;
 pub use disable::*;
 
+mod order_size_limits;
+pub use order_size_limits::*;
+
+mod pause;
+pub use pause::*;
+
+mod rate_limits;
+pub use rate_limits::*;
+
 mod update;
 pub use update::*;
@@ -0,0 +1,64 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{OrderSizeLimits, RouterEndpoint},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetOrderSizeLimitsArgs {
+    pub chain: u16,
+    pub min_amount_in: u64,
+    pub max_fast_amount_in: Option<u64>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetOrderSizeLimitsArgs)]
+#[event_cpi]
+pub struct SetRouterEndpointOrderSizeLimits<'info> {
+    admin: OwnerOnly<'info>,
+
+    /// Router endpoints created before order size limits were introduced must be upgraded via
+    /// [migrate_accounts](crate::migrate_accounts) first, which zeroes these limits.
+    #[account(
+        mut,
+        seeds = [
+            RouterEndpoint::SEED_PREFIX,
+            &args.chain.to_be_bytes()
+        ],
+        bump = router_endpoint.bump,
+    )]
+    router_endpoint: Box<Account<'info, RouterEndpoint>>,
+}
+
+pub fn set_router_endpoint_order_size_limits(
+    ctx: Context<SetRouterEndpointOrderSizeLimits>,
+    args: SetOrderSizeLimitsArgs,
+) -> Result<()> {
+    let SetOrderSizeLimitsArgs {
+        chain,
+        min_amount_in,
+        max_fast_amount_in,
+    } = args;
+
+    if let Some(max_fast_amount_in) = max_fast_amount_in {
+        require!(
+            min_amount_in <= max_fast_amount_in,
+            MatchingEngineError::InvalidOrderSizeLimits
+        );
+    }
+
+    ctx.accounts.router_endpoint.order_size_limits = OrderSizeLimits {
+        min_amount_in,
+        max_fast_amount_in,
+    };
+
+    emit_cpi!(crate::events::OrderSizeLimitsUpdated {
+        chain,
+        min_amount_in,
+        max_fast_amount_in,
+    });
+
+    // Done.
+    Ok(())
+}
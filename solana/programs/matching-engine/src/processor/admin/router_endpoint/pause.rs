@@ -0,0 +1,42 @@
+use crate::{composite::*, state::RouterEndpoint};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+#[event_cpi]
+pub struct SetRouterEndpointPause<'info> {
+    admin: PauserOnly<'info>,
+
+    /// Router endpoints created before pause controls were introduced must be upgraded via
+    /// [migrate_accounts](crate::migrate_accounts) first, which unpauses them.
+    #[account(
+        mut,
+        seeds = [
+            RouterEndpoint::SEED_PREFIX,
+            &chain.to_be_bytes()
+        ],
+        bump = router_endpoint.bump,
+    )]
+    router_endpoint: Box<Account<'info, RouterEndpoint>>,
+}
+
+pub fn set_router_endpoint_pause(
+    ctx: Context<SetRouterEndpointPause>,
+    chain: u16,
+    pause: bool,
+) -> Result<()> {
+    let set_by = ctx.accounts.admin.authority.key();
+
+    let router_endpoint = &mut ctx.accounts.router_endpoint;
+    router_endpoint.pause.paused = pause;
+    router_endpoint.pause.set_by = set_by;
+
+    emit_cpi!(crate::events::RouterEndpointPauseUpdated {
+        chain,
+        paused: pause,
+        set_by,
+    });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use crate::{
+    composite::*,
+    state::{RateLimitConfig, RateLimiter, RouterEndpoint},
+    utils,
+};
+use anchor_lang::prelude::*;
+use common::admin::utils::migrate::VersionedAccount;
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetRateLimitsArgs {
+    pub inbound: Option<RateLimitConfig>,
+    pub outbound: Option<RateLimitConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetRateLimitsArgs)]
+#[event_cpi]
+pub struct SetRouterEndpointRateLimits<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnly<'info>,
+
+    #[account(
+        seeds = [
+            RouterEndpoint::SEED_PREFIX,
+            &router_endpoint.chain.to_be_bytes()
+        ],
+        bump = router_endpoint.bump,
+    )]
+    router_endpoint: Box<Account<'info, RouterEndpoint>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RateLimiter::INIT_SPACE,
+        seeds = [
+            RateLimiter::SEED_PREFIX,
+            &router_endpoint.chain.to_be_bytes()
+        ],
+        bump,
+        constraint = {
+            if let Some(config) = &args.inbound {
+                utils::rate_limit::require_valid_config(config)?;
+            }
+            if let Some(config) = &args.outbound {
+                utils::rate_limit::require_valid_config(config)?;
+            }
+
+            true
+        }
+    )]
+    rate_limiter: Box<Account<'info, RateLimiter>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn set_router_endpoint_rate_limits(
+    ctx: Context<SetRouterEndpointRateLimits>,
+    args: SetRateLimitsArgs,
+) -> Result<()> {
+    let SetRateLimitsArgs { inbound, outbound } = args;
+
+    let rate_limiter = &mut ctx.accounts.rate_limiter;
+
+    // If the rate limiter was just created, set its bump, chain and version.
+    rate_limiter.bump = ctx.bumps.rate_limiter;
+    rate_limiter.chain = ctx.accounts.router_endpoint.chain;
+    rate_limiter.version = RateLimiter::VERSION;
+
+    utils::rate_limit::set_config(&mut rate_limiter.inbound, inbound);
+    utils::rate_limit::set_config(&mut rate_limiter.outbound, outbound);
+
+    emit_cpi!(crate::events::RateLimitsUpdated {
+        chain: rate_limiter.chain,
+        inbound,
+        outbound,
+    });
+
+    // Done.
+    Ok(())
+}
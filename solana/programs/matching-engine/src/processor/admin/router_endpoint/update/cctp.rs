@@ -1,5 +1,7 @@
 use crate::{
     composite::*,
+    error::MatchingEngineError,
+    state::ProposalAction,
     utils::{self, admin::AddCctpRouterEndpointArgs},
 };
 use anchor_lang::prelude::*;
@@ -9,15 +11,21 @@ use common::wormhole_cctp_solana::cctp::token_messenger_minter_program::{
 
 #[derive(Accounts)]
 #[instruction(args: AddCctpRouterEndpointArgs)]
+#[event_cpi]
 pub struct UpdateCctpRouterEndpoint<'info> {
-    admin: OwnerOnly<'info>,
+    #[account(
+        constraint = {
+            enact.proposal.action == ProposalAction::UpdateCctpRouterEndpoint { args }
+        } @ MatchingEngineError::InvalidProposal,
+    )]
+    enact: EnactProposal<'info>,
 
     #[account(
         constraint = {
             require_eq!(
                 args.chain,
                 router_endpoint.chain,
-                crate::error::MatchingEngineError::InvalidEndpoint,
+                MatchingEngineError::InvalidEndpoint,
             );
 
             true
@@ -42,5 +50,10 @@ pub fn update_cctp_router_endpoint(
     ctx: Context<UpdateCctpRouterEndpoint>,
     args: AddCctpRouterEndpointArgs,
 ) -> Result<()> {
+    let action = ctx.accounts.enact.enact();
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
     utils::admin::handle_add_cctp_router_endpoint(&mut ctx.accounts.router_endpoint, args, None)
 }
@@ -1,10 +1,11 @@
-use crate::{composite::*, error::MatchingEngineError, utils};
+use crate::{composite::*, error::MatchingEngineError, state::ProposalAction, utils};
 use anchor_lang::prelude::*;
 use common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN;
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct UpdateLocalRouterEndpoint<'info> {
-    admin: OwnerOnly<'info>,
+    enact: EnactProposal<'info>,
 
     #[account(
         constraint = {
@@ -18,10 +19,22 @@ pub struct UpdateLocalRouterEndpoint<'info> {
     )]
     router_endpoint: ExistingMutRouterEndpoint<'info>,
 
+    #[account(
+        constraint = {
+            enact.proposal.action == ProposalAction::UpdateLocalRouterEndpoint {
+                token_router_program: local.token_router_program.key(),
+            }
+        } @ MatchingEngineError::InvalidProposal,
+    )]
     local: LocalTokenRouter<'info>,
 }
 
 pub fn update_local_router_endpoint(ctx: Context<UpdateLocalRouterEndpoint>) -> Result<()> {
+    let action = ctx.accounts.enact.enact();
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
     utils::admin::handle_add_local_router_endpoint(
         &mut ctx.accounts.router_endpoint,
         &ctx.accounts.local.token_router_program,
@@ -1,15 +1,24 @@
-use crate::composite::*;
+use crate::{composite::*, state::PauseGroup};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
-pub struct SetPause<'info> {
-    admin: AdminMut<'info>,
+#[event_cpi]
+pub struct SetGroupPause<'info> {
+    admin: PauserOnlyMut<'info>,
 }
 
-pub fn set_pause(ctx: Context<SetPause>, paused: bool) -> Result<()> {
-    let custodian = &mut ctx.accounts.admin.custodian;
-    custodian.paused = paused;
-    custodian.paused_set_by = ctx.accounts.admin.owner_or_assistant.key();
+pub fn set_group_pause(ctx: Context<SetGroupPause>, group: PauseGroup, pause: bool) -> Result<()> {
+    let set_by = ctx.accounts.admin.authority.key();
+
+    let flag = ctx.accounts.admin.custodian.pause_flag_mut(group);
+    flag.paused = pause;
+    flag.set_by = set_by;
+
+    emit_cpi!(crate::events::PauseUpdated {
+        group,
+        paused: pause,
+        set_by,
+    });
 
     // Done.
     Ok(())
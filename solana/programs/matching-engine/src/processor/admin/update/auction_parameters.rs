@@ -1,9 +1,10 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{AuctionConfig, AuctionParameters, Proposal, ProposalAction},
+    state::{AuctionConfig, AuctionParameters, ProposalAction},
 };
 use anchor_lang::prelude::*;
+use common::admin::utils::migrate::VersionedAccount;
 
 #[derive(Accounts)]
 #[event_cpi]
@@ -11,35 +12,14 @@ pub struct UpdateAuctionParameters<'info> {
     #[account(mut)]
     payer: Signer<'info>,
 
-    admin: OwnerOnlyMut<'info>,
-
     #[account(
-        mut,
-        seeds = [
-            Proposal::SEED_PREFIX,
-            &proposal.id.to_be_bytes(),
-        ],
-        bump = proposal.bump,
         constraint = {
-            require_keys_eq!(
-                proposal.owner, admin.owner.key()
-            );
-            require!(
-                proposal.slot_enacted_at.is_none(),
-                MatchingEngineError::ProposalAlreadyEnacted
-            );
-
-            require!(
-                Clock::get().unwrap().slot >= proposal.slot_enact_delay,
-                MatchingEngineError::ProposalDelayNotExpired
-            );
-
-            match &proposal.action {
+            match &enact.proposal.action {
                 ProposalAction::UpdateAuctionParameters { id, .. } => {
                     require_eq!(
                         *id,
                         // NOTE: This value is checked in `propose_auction_parameters`.
-                        admin.custodian.auction_config_id.saturating_add(1),
+                        enact.admin.custodian.auction_config_id.saturating_add(1),
                         MatchingEngineError::AuctionConfigMismatch
                     );
                 },
@@ -49,7 +29,7 @@ pub struct UpdateAuctionParameters<'info> {
             true
         }
     )]
-    proposal: Account<'info, Proposal>,
+    enact: EnactProposal<'info>,
 
     #[account(
         init,
@@ -58,7 +38,7 @@ pub struct UpdateAuctionParameters<'info> {
         seeds = [
             AuctionConfig::SEED_PREFIX,
             // NOTE: This value is checked in `propose_auction_parameters`.
-            admin.custodian.auction_config_id.saturating_add(1).to_be_bytes().as_ref()
+            enact.admin.custodian.auction_config_id.saturating_add(1).to_be_bytes().as_ref()
         ],
         bump,
     )]
@@ -68,7 +48,7 @@ pub struct UpdateAuctionParameters<'info> {
 }
 
 pub fn update_auction_parameters(ctx: Context<UpdateAuctionParameters>) -> Result<()> {
-    let action = ctx.accounts.proposal.action;
+    let action = ctx.accounts.enact.enact();
 
     // Emit event to reflect enacting the proposal.
     emit_cpi!(crate::events::Enacted { action });
@@ -86,26 +66,14 @@ fn handle_update_auction_parameters(
     id: u32,
     parameters: AuctionParameters,
 ) -> Result<()> {
-    ctx.accounts
-        .auction_config
-        .set_inner(AuctionConfig { id, parameters });
+    ctx.accounts.auction_config.set_inner(AuctionConfig {
+        id,
+        parameters,
+        version: AuctionConfig::VERSION,
+    });
 
     // Update the auction config ID.
-    ctx.accounts.admin.custodian.auction_config_id = id;
-
-    // Set the slot enacted at so it cannot be replayed.
-    ctx.accounts.proposal.slot_enacted_at = Clock::get().unwrap().slot.into();
-
-    // Uptick the proposal ID so that someone can create a new proposal again.
-    //
-    // NOTE: Overflow check is done in propose instructions.
-    let next_proposal_id = ctx
-        .accounts
-        .admin
-        .custodian
-        .next_proposal_id
-        .saturating_add(1);
-    ctx.accounts.admin.custodian.next_proposal_id = next_proposal_id;
+    ctx.accounts.enact.admin.custodian.auction_config_id = id;
 
     // Done.
     Ok(())
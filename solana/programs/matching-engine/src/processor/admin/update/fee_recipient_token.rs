@@ -1,14 +1,20 @@
-use crate::{composite::*, error::MatchingEngineError};
+use crate::{composite::*, error::MatchingEngineError, state::ProposalAction};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct UpdateFeeRecipient<'info> {
-    admin: AdminMut<'info>,
+    enact: EnactProposal<'info>,
 
     #[account(
         associated_token::mint = common::USDC_MINT,
         associated_token::authority = new_fee_recipient,
+        constraint = {
+            enact.proposal.action == ProposalAction::UpdateFeeRecipient {
+                fee_recipient_token: new_fee_recipient_token.key(),
+            }
+        } @ MatchingEngineError::InvalidProposal,
     )]
     new_fee_recipient_token: Account<'info, token::TokenAccount>,
 
@@ -24,8 +30,14 @@ pub struct UpdateFeeRecipient<'info> {
 }
 
 pub fn update_fee_recipient(ctx: Context<UpdateFeeRecipient>) -> Result<()> {
+    let action = ctx.accounts.enact.enact();
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
     // Update the fee_recipient key.
-    ctx.accounts.admin.custodian.fee_recipient_token = ctx.accounts.new_fee_recipient_token.key();
+    ctx.accounts.enact.admin.custodian.fee_recipient_token =
+        ctx.accounts.new_fee_recipient_token.key();
 
     Ok(())
 }
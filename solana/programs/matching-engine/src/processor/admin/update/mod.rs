@@ -6,3 +6,9 @@ pub use fee_recipient_token::*;
 
 mod owner_assistant;
 pub use owner_assistant::*;
+
+mod owner_assistant_rotation;
+pub use owner_assistant_rotation::*;
+
+mod proposal_delays;
+pub use proposal_delays::*;
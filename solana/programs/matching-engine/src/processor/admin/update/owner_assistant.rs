@@ -1,10 +1,11 @@
-use crate::{composite::*, error::MatchingEngineError};
+use crate::{composite::*, error::MatchingEngineError, state::ProposalAction};
 use anchor_lang::prelude::*;
 use common::admin::utils::assistant;
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct UpdateOwnerAssistant<'info> {
-    admin: OwnerOnlyMut<'info>,
+    enact: EnactProposal<'info>,
 
     /// New Assistant.
     ///
@@ -13,14 +14,26 @@ pub struct UpdateOwnerAssistant<'info> {
         constraint = {
             new_owner_assistant.key() != Pubkey::default()
         } @ MatchingEngineError::AssistantZeroPubkey,
+        constraint = {
+            enact.proposal.action == ProposalAction::UpdateOwnerAssistant {
+                owner_assistant: new_owner_assistant.key(),
+            }
+        } @ MatchingEngineError::InvalidProposal,
     )]
     new_owner_assistant: UncheckedAccount<'info>,
 }
 
 pub fn update_owner_assistant(ctx: Context<UpdateOwnerAssistant>) -> Result<()> {
-    assistant::transfer_owner_assistant(
-        &mut ctx.accounts.admin.custodian,
+    let action = ctx.accounts.enact.enact();
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
+    // The previous assistant remains authorized until the rotation window expires.
+    assistant::rotate_owner_assistant(
+        &mut ctx.accounts.enact.admin.custodian,
         &ctx.accounts.new_owner_assistant,
+        Clock::get()?.slot,
     );
 
     // Done.
@@ -0,0 +1,31 @@
+use crate::{composite::*, error::MatchingEngineError, state::ProposalAction};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct UpdateProposalDelays<'info> {
+    #[account(
+        constraint = matches!(
+            enact.proposal.action,
+            ProposalAction::UpdateProposalDelays { .. }
+        ) @ MatchingEngineError::InvalidProposal,
+    )]
+    enact: EnactProposal<'info>,
+}
+
+pub fn update_proposal_delays(ctx: Context<UpdateProposalDelays>) -> Result<()> {
+    let action = ctx.accounts.enact.enact();
+
+    // Emit event to reflect enacting the proposal.
+    emit_cpi!(crate::events::Enacted { action });
+
+    match action {
+        ProposalAction::UpdateProposalDelays { delays } => {
+            ctx.accounts.enact.admin.custodian.proposal_delays = delays;
+
+            // Done.
+            Ok(())
+        }
+        _ => err!(MatchingEngineError::InvalidProposal),
+    }
+}
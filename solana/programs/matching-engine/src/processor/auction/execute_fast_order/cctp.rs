@@ -36,6 +36,7 @@ pub struct ExecuteFastOrderCctp<'info> {
     )]
     cctp_message: UncheckedAccount<'info>,
 
+    #[account(constraint = !custodian.execution_pause.paused @ MatchingEngineError::Paused)]
     custodian: CheckedCustodian<'info>,
 
     execute_order: ExecuteOrder<'info>,
@@ -13,6 +13,7 @@ pub struct ExecuteFastOrderLocal<'info> {
     #[account(mut)]
     payer: Signer<'info>,
 
+    #[account(constraint = !custodian.execution_pause.paused @ MatchingEngineError::Paused)]
     custodian: CheckedCustodian<'info>,
 
     execute_order: ExecuteOrder<'info>,
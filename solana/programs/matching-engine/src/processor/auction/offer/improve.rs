@@ -20,6 +20,12 @@ pub struct ImproveOffer<'info> {
     )]
     transfer_authority: UncheckedAccount<'info>,
 
+    /// NOTE: This account is only used to pause offer improvements.
+    #[account(
+        constraint = !custodian.offer_improvements_pause.paused @ MatchingEngineError::Paused,
+    )]
+    custodian: CheckedCustodian<'info>,
+
     #[account(
         constraint = {
             // This is safe because we know that this is an active auction.
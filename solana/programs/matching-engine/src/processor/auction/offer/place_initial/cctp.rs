@@ -1,7 +1,7 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{Auction, AuctionConfig, AuctionInfo, AuctionStatus, MessageProtocol},
+    state::{Auction, AuctionConfig, AuctionInfo, AuctionStatus, MessageProtocol, RateLimiter},
     utils,
 };
 use anchor_lang::prelude::*;
@@ -29,7 +29,7 @@ pub struct PlaceInitialOfferCctp<'info> {
     transfer_authority: UncheckedAccount<'info>,
 
     /// NOTE: This account is only used to pause inbound auctions.
-    #[account(constraint = !custodian.paused @ MatchingEngineError::Paused)]
+    #[account(constraint = !custodian.new_auctions_pause.paused @ MatchingEngineError::Paused)]
     custodian: CheckedCustodian<'info>,
 
     #[account(
@@ -74,11 +74,50 @@ pub struct PlaceInitialOfferCctp<'info> {
                 MatchingEngineError::OfferPriceTooHigh
             );
 
+            // The amount delivered to the redeemer if this offer wins cannot be less than the
+            // order's min amount out. Improved offers only increase this amount.
+            require!(
+                order
+                    .amount_in()
+                    .saturating_sub(offer_price)
+                    .saturating_sub(order.init_auction_fee())
+                    >= order.min_amount_out(),
+                MatchingEngineError::MinAmountOutNotMet
+            );
+
             true
         }
     )]
     fast_order_path: FastOrderPath<'info>,
 
+    /// Rate limiter for the source router endpoint, whose inbound limit applies to this order. If
+    /// this account has not been created, there is no inbound limit.
+    ///
+    /// CHECK: Mutable. Seeds must be \["rate-limiter", from_endpoint.chain.to_be_bytes()\].
+    #[account(
+        mut,
+        seeds = [
+            RateLimiter::SEED_PREFIX,
+            &fast_order_path.from_endpoint.chain.to_be_bytes()
+        ],
+        bump,
+    )]
+    from_rate_limiter: UncheckedAccount<'info>,
+
+    /// Rate limiter for the target router endpoint, whose outbound limit applies to this order. If
+    /// this account has not been created, there is no outbound limit.
+    ///
+    /// CHECK: Mutable. Seeds must be \["rate-limiter", to_endpoint.chain.to_be_bytes()\].
+    #[account(
+        mut,
+        seeds = [
+            RateLimiter::SEED_PREFIX,
+            &fast_order_path.to_endpoint.chain.to_be_bytes()
+        ],
+        bump,
+    )]
+    to_rate_limiter: UncheckedAccount<'info>,
+
     /// This account should only be created once, and should never be changed to
     /// init_if_needed. Otherwise someone can game an existing auction.
     #[account(
@@ -127,6 +166,38 @@ pub fn place_initial_offer_cctp(
     // Parse the transfer amount from the VAA.
     let amount_in = order.amount_in();
 
+    // Orders outside of either router endpoint's order size limits cannot be auctioned.
+    utils::require_valid_fast_order_amount(
+        &ctx.accounts.fast_order_path.from_endpoint.order_size_limits,
+        amount_in,
+    )?;
+    utils::require_valid_fast_order_amount(
+        &ctx.accounts.fast_order_path.to_endpoint.order_size_limits,
+        amount_in,
+    )?;
+
+    // Orders exceeding either rate limit cannot be auctioned. These orders must be settled via
+    // the slow (finalized) path instead.
+    let current_slot = Clock::get().unwrap().slot;
+    require!(
+        utils::rate_limit::try_consume_account(
+            &ctx.accounts.from_rate_limiter,
+            |rate_limiter| &mut rate_limiter.inbound,
+            amount_in,
+            current_slot
+        )?,
+        MatchingEngineError::InboundRateLimitExceeded
+    );
+    require!(
+        utils::rate_limit::try_consume_account(
+            &ctx.accounts.to_rate_limiter,
+            |rate_limiter| &mut rate_limiter.outbound,
+            amount_in,
+            current_slot
+        )?,
+        MatchingEngineError::OutboundRateLimitExceeded
+    );
+
     // Saturating to u64::MAX is safe here. If the amount really ends up being this large, the
     // checked addition below will catch it.
     let security_deposit =
@@ -154,7 +225,7 @@ pub fn place_initial_offer_cctp(
             source_chain: fast_vaa.emitter_chain(),
             best_offer_token: initial_offer_token,
             initial_offer_token,
-            start_slot: Clock::get().unwrap().slot,
+            start_slot: current_slot,
             amount_in,
             security_deposit,
             offer_price,
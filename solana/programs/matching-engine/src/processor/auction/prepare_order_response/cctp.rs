@@ -17,6 +17,7 @@ pub struct PrepareOrderResponseCctp<'info> {
     #[account(mut)]
     payer: Signer<'info>,
 
+    #[account(constraint = !custodian.settlement_pause.paused @ MatchingEngineError::Paused)]
     custodian: CheckedCustodian<'info>,
 
     fast_order_path: FastOrderPath<'info>,
@@ -0,0 +1,221 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{
+        Custodian, MessageProtocol, PreparedOrderResponse, PreparedOrderResponseInfo,
+        PreparedOrderResponseSeeds,
+    },
+    utils,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::{
+    messages::raw::{LiquidityLayerMessage, MessageToVec},
+    wormhole_cctp_solana::wormhole::SOLANA_CHAIN,
+};
+
+#[derive(Accounts)]
+pub struct PrepareOrderResponseLocal<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(constraint = !custodian.settlement_pause.paused @ MatchingEngineError::Paused)]
+    custodian: CheckedCustodian<'info>,
+
+    /// The fast market order must have been published by the local Token Router.
+    #[account(constraint = utils::require_local_endpoint(&fast_order_path.from_endpoint)?)]
+    fast_order_path: FastOrderPath<'info>,
+
+    #[account(
+        constraint = {
+            // Fast and finalized VAAs must reconcile with each other.
+            let fast_vaa = fast_order_path.fast_vaa.load_unchecked();
+            let finalized_vaa = finalized_vaa.load_unchecked();
+
+            require_eq!(
+                fast_vaa.emitter_chain(),
+                finalized_vaa.emitter_chain(),
+                MatchingEngineError::VaaMismatch
+            );
+            require!(
+                fast_vaa.emitter_address() == finalized_vaa.emitter_address(),
+                MatchingEngineError::VaaMismatch
+            );
+            require_eq!(
+                fast_vaa.sequence(),
+                finalized_vaa.sequence().saturating_add(1),
+                MatchingEngineError::VaaMismatch
+            );
+            require!(
+                fast_vaa.timestamp() == finalized_vaa.timestamp(),
+                MatchingEngineError::VaaMismatch
+            );
+
+            // The Token Router publishes the same fast market order with finalized commitment in
+            // place of a slow order response.
+            require!(
+                fast_vaa.payload() == finalized_vaa.payload(),
+                MatchingEngineError::VaaMismatch
+            );
+
+            true
+        }
+    )]
+    finalized_vaa: LiquidityLayerVaa<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PreparedOrderResponse::compute_size({
+            let fast_vaa = fast_order_path.fast_vaa.load_unchecked();
+            let message = LiquidityLayerMessage::try_from(fast_vaa.payload())
+                .unwrap();
+            let order = message
+                .fast_market_order()
+                .ok_or_else(|| MatchingEngineError::InvalidPayloadId)?;
+
+            order.redeemer_message_len().into()
+        }),
+        seeds = [
+            PreparedOrderResponse::SEED_PREFIX,
+            fast_order_path.fast_vaa.load_unchecked().digest().as_ref()
+        ],
+        bump,
+    )]
+    prepared_order_response: Box<Account<'info, PreparedOrderResponse>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        token::mint = usdc,
+        token::authority = prepared_order_response,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_order_response.key().as_ref(),
+        ],
+        bump,
+    )]
+    prepared_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Custody token account created by the Token Router when the fast market order was placed.
+    /// This account is closed by this instruction and its lamports are sent to the payer, so the
+    /// same deposit cannot be used to prepare an order response twice.
+    ///
+    /// CHECK: Mutable. Seeds must be
+    /// \["local-order-custody", finalized_vaa.sequence().to_be_bytes()\] (Token Router program).
+    #[account(
+        mut,
+        seeds = [
+            common::LOCAL_ORDER_CUSTODY_TOKEN_SEED_PREFIX,
+            &finalized_vaa.load_unchecked().sequence().to_be_bytes(),
+        ],
+        bump,
+        seeds::program = match fast_order_path.from_endpoint.protocol {
+            MessageProtocol::Local { program_id } => program_id,
+            _ => return err!(MatchingEngineError::InvalidSourceRouter),
+        },
+    )]
+    local_order_custody_token: UncheckedAccount<'info>,
+
+    /// This token account will be the one that collects the base fee only if an auction's order
+    /// was executed late. Otherwise, the protocol's fee recipient token account will be used for
+    /// non-existent auctions and the best offer token account will be used for orders executed on
+    /// time.
+    #[account(
+        token::mint = usdc,
+        constraint = {
+            require!(
+                base_fee_token.key() != prepared_custody_token.key(),
+                MatchingEngineError::InvalidBaseFeeToken
+            );
+
+            true
+        }
+    )]
+    base_fee_token: Box<Account<'info, token::TokenAccount>>,
+
+    usdc: Usdc<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+/// Prepare the order response for a fast market order placed by the Solana Token Router. There is
+/// no CCTP transfer for these orders because CCTP cannot mint within Solana's own domain. Instead,
+/// the Token Router deposits the order's funds when it publishes the fast market order, so these
+/// funds are moved to the prepared custody token account here. A finalized copy of the fast market
+/// order takes the place of the slow order response, so the funds are only released once the
+/// order is final.
+pub fn prepare_order_response_local(ctx: Context<PrepareOrderResponseLocal>) -> Result<()> {
+    match ctx.accounts.prepared_order_response.source_chain {
+        0 => handle_prepare_order_response_local(ctx),
+        _ => super::prepare_order_response_noop(),
+    }
+}
+
+fn handle_prepare_order_response_local(ctx: Context<PrepareOrderResponseLocal>) -> Result<()> {
+    let fast_vaa = ctx.accounts.fast_order_path.fast_vaa.load_unchecked();
+    let order = LiquidityLayerMessage::try_from(fast_vaa.payload())
+        .unwrap()
+        .to_fast_market_order_unchecked();
+
+    let amount_in = order.amount_in();
+
+    // Write to the prepared slow order account, which will be closed by one of the following
+    // instructions:
+    // * settle_auction_active_cctp
+    // * settle_auction_complete
+    // * settle_auction_none
+    //
+    // There is no slow relayer to compensate, so the base fee is zero.
+    ctx.accounts
+        .prepared_order_response
+        .set_inner(PreparedOrderResponse {
+            seeds: PreparedOrderResponseSeeds {
+                fast_vaa_hash: fast_vaa.digest().0,
+                bump: ctx.bumps.prepared_order_response,
+            },
+            info: PreparedOrderResponseInfo {
+                prepared_by: ctx.accounts.payer.key(),
+                base_fee_token: ctx.accounts.base_fee_token.key(),
+                source_chain: SOLANA_CHAIN,
+                base_fee: 0,
+                fast_vaa_timestamp: fast_vaa.timestamp(),
+                amount_in,
+                sender: order.sender(),
+                redeemer: order.redeemer(),
+                init_auction_fee: order.init_auction_fee(),
+            },
+            to_endpoint: ctx.accounts.fast_order_path.to_endpoint.info,
+            redeemer_message: order.message_to_vec(),
+        });
+
+    let token_program = &ctx.accounts.token_program;
+    let local_order_custody_token = &ctx.accounts.local_order_custody_token;
+    let custodian = &ctx.accounts.custodian;
+
+    // Transfer the deposited funds to prepared custody token.
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: local_order_custody_token.to_account_info(),
+                to: ctx.accounts.prepared_custody_token.to_account_info(),
+                authority: custodian.to_account_info(),
+            },
+            &[Custodian::SIGNER_SEEDS],
+        ),
+        amount_in,
+    )?;
+
+    // Finally close the deposit.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: local_order_custody_token.to_account_info(),
+            destination: ctx.accounts.payer.to_account_info(),
+            authority: custodian.to_account_info(),
+        },
+        &[Custodian::SIGNER_SEEDS],
+    ))
+}
@@ -1,6 +1,9 @@
 mod cctp;
 pub use cctp::*;
 
+mod local;
+pub use local::*;
+
 use anchor_lang::prelude::*;
 
 fn prepare_order_response_noop() -> Result<()> {
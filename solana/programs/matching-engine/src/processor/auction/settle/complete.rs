@@ -1,4 +1,5 @@
 use crate::{
+    composite::*,
     error::MatchingEngineError,
     events::SettledTokenAccountInfo,
     state::{Auction, AuctionStatus, PreparedOrderResponse},
@@ -10,6 +11,10 @@ use anchor_spl::token::{self, TokenAccount};
 #[derive(Accounts)]
 #[event_cpi]
 pub struct SettleAuctionComplete<'info> {
+    /// NOTE: This account is only used to pause settlement.
+    #[account(constraint = !custodian.settlement_pause.paused @ MatchingEngineError::Paused)]
+    custodian: CheckedCustodian<'info>,
+
     /// CHECK: Must equal prepared_order_response.prepared_by, who paid the rent to post the
     /// finalized VAA.
     #[account(
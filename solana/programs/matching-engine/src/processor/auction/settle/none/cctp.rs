@@ -36,6 +36,7 @@ pub struct SettleAuctionNoneCctp<'info> {
     )]
     cctp_message: UncheckedAccount<'info>,
 
+    #[account(constraint = !custodian.settlement_pause.paused @ MatchingEngineError::Paused)]
     custodian: CheckedCustodian<'info>,
 
     /// Destination token account, which the redeemer may not own. But because the redeemer is a
@@ -13,6 +13,7 @@ pub struct SettleAuctionNoneLocal<'info> {
     #[account(mut)]
     payer: Signer<'info>,
 
+    #[account(constraint = !custodian.settlement_pause.paused @ MatchingEngineError::Paused)]
     custodian: CheckedCustodian<'info>,
 
     /// Destination token account, which the redeemer may not own. But because the redeemer is a
@@ -11,7 +11,10 @@ use common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN;
 #[derive(Accounts)]
 #[event_cpi]
 pub struct CompleteFastFill<'info> {
-    /// Custodian, which may be used in the future.
+    /// NOTE: This account is only used to pause local fast fills.
+    #[account(
+        constraint = !custodian.local_fast_fills_pause.paused @ MatchingEngineError::Paused,
+    )]
     custodian: CheckedCustodian<'info>,
 
     /// Fast fill account.
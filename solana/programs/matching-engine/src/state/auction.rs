@@ -132,6 +132,8 @@ impl AuctionInfo {
     }
 }
 
+/// NOTE: This account is not versioned (see [VersionedAccount](common::admin::utils::migrate::VersionedAccount)) because it is closed after the
+/// auction is settled. Schema growth is handled by the optional auction info instead.
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct Auction {
@@ -1,4 +1,6 @@
+use crate::error::MatchingEngineError;
 use anchor_lang::prelude::*;
+use common::admin::utils::migrate::VersionedAccount;
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq)]
 pub struct AuctionParameters {
@@ -39,12 +41,48 @@ pub struct AuctionConfig {
     pub id: u32,
     /// Auction parameters, which are validated by [crate::utils::auction::require_valid_parameters].
     pub parameters: AuctionParameters,
+
+    /// Schema version of this account. New fields must be added after this one.
+    pub version: u8,
 }
 
 impl AuctionConfig {
     pub const SEED_PREFIX: &'static [u8] = b"auction-config";
 }
 
+impl VersionedAccount for AuctionConfig {
+    const VERSION: u8 = 1;
+    const SIZE: usize = 8 + Self::INIT_SPACE;
+    const UNVERSIONED_SIZE: usize = Self::SIZE - 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+
+    fn read_version(buf: &mut &[u8], unversioned: bool) -> Result<u8> {
+        if unversioned {
+            <(u32, AuctionParameters)>::deserialize(buf)?;
+            Ok(0)
+        } else {
+            Self::deserialize(buf)
+                .map(|config| config.version)
+                .map_err(Into::into)
+        }
+    }
+
+    fn upgrade_from(&mut self, version: u8) -> Result<()> {
+        match version {
+            // Accounts created before versioning have no other fields to set.
+            0 => Ok(()),
+            _ => err!(MatchingEngineError::UnsupportedAccountVersion),
+        }
+    }
+}
+
 impl std::ops::Deref for AuctionConfig {
     type Target = AuctionParameters;
 
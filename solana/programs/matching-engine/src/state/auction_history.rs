@@ -9,6 +9,9 @@ use anchor_lang::{prelude::*, Discriminator};
 
 use super::AuctionInfo;
 
+/// NOTE: This account is not versioned (see
+/// [VersionedAccount](common::admin::utils::migrate::VersionedAccount)) because its entries are read
+/// by offset via [AuctionHistoryInternal]. This schema must not change.
 #[account]
 #[derive(Debug, Default)]
 pub struct AuctionHistory {
@@ -1,5 +1,30 @@
+use super::ProposalDelays;
+use crate::error::MatchingEngineError;
 use anchor_lang::prelude::*;
-use common::admin;
+use common::admin::{self, utils::migrate::VersionedAccount};
+
+/// Groups of instructions that can be paused independently.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PauseGroup {
+    /// Placing initial offers.
+    NewAuctions,
+    /// Improving offers on active auctions.
+    OfferImprovements,
+    /// Executing fast orders.
+    Execution,
+    /// Completing fast fills for the local Token Router.
+    LocalFastFills,
+    /// Preparing order responses and settling auctions.
+    Settlement,
+}
+
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct PauseFlag {
+    pub paused: bool,
+    pub set_by: Pubkey,
+}
 
 #[account]
 #[derive(Debug, InitSpace)]
@@ -8,9 +33,10 @@ pub struct Custodian {
     pub owner: Pubkey,
     pub pending_owner: Option<Pubkey>,
 
-    /// Boolean indicating whether inbound auctions are paused.
-    pub paused: bool,
-    pub paused_set_by: Pubkey,
+    /// Indicates whether new auctions are paused.
+    ///
+    /// NOTE: This field occupies the same space as the legacy `paused` and `paused_set_by` fields.
+    pub new_auctions_pause: PauseFlag,
 
     /// Program's assistant.
     pub owner_assistant: Pubkey,
@@ -21,12 +47,103 @@ pub struct Custodian {
     pub auction_config_id: u32,
 
     pub next_proposal_id: u64,
+
+    /// Indicates whether the remaining instruction groups are paused. Custodians created before
+    /// these fields were introduced are reallocated via `migrate`.
+    pub offer_improvements_pause: PauseFlag,
+    pub execution_pause: PauseFlag,
+    pub local_fast_fills_pause: PauseFlag,
+    pub settlement_pause: PauseFlag,
+
+    /// Enact delays for each proposal action.
+    pub proposal_delays: ProposalDelays,
+
+    /// M-of-N signers allowed to act as the owner. Inactive unless its threshold is nonzero.
+    pub owner_set: admin::OwnerSet,
+    pub pending_owner_set: Option<admin::OwnerSet>,
+
+    /// Slot window in which the pending owner can confirm the ownership transfer request.
+    pub pending_owner_window: admin::PendingOwnerWindow,
+
+    /// Previous owner assistant, which remains authorized until the rotation window expires.
+    pub owner_assistant_rotation: Option<admin::OwnerAssistantRotation>,
+
+    /// Schema version of this account. New fields must be added after this one.
+    pub version: u8,
 }
 
 impl Custodian {
     pub const SEED_PREFIX: &'static [u8] = b"emitter";
     pub const BUMP: u8 = crate::CUSTODIAN_BUMP;
     pub const SIGNER_SEEDS: &'static [&'static [u8]] = &[Self::SEED_PREFIX, &[Self::BUMP]];
+
+    pub fn pause_flag_mut(&mut self, group: PauseGroup) -> &mut PauseFlag {
+        match group {
+            PauseGroup::NewAuctions => &mut self.new_auctions_pause,
+            PauseGroup::OfferImprovements => &mut self.offer_improvements_pause,
+            PauseGroup::Execution => &mut self.execution_pause,
+            PauseGroup::LocalFastFills => &mut self.local_fast_fills_pause,
+            PauseGroup::Settlement => &mut self.settlement_pause,
+        }
+    }
+}
+
+impl VersionedAccount for Custodian {
+    const VERSION: u8 = 1;
+    const SIZE: usize = 8 + Self::INIT_SPACE;
+    const UNVERSIONED_SIZE: usize = 8 // discriminator
+        + 32 // owner
+        + 33 // pending_owner
+        + 1 // paused
+        + 32 // paused_set_by
+        + 32 // owner_assistant
+        + 32 // fee_recipient_token
+        + 4 // auction_config_id
+        + 8 // next_proposal_id
+    ;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+
+    fn read_version(buf: &mut &[u8], unversioned: bool) -> Result<u8> {
+        if unversioned {
+            <(
+                Pubkey,
+                Option<Pubkey>,
+                bool,
+                Pubkey,
+                Pubkey,
+                Pubkey,
+                u32,
+                u64,
+            )>::deserialize(buf)?;
+            Ok(0)
+        } else {
+            Self::deserialize(buf)
+                .map(|custodian| custodian.version)
+                .map_err(Into::into)
+        }
+    }
+
+    fn upgrade_from(&mut self, version: u8) -> Result<()> {
+        match version {
+            // Before versioning, the next proposal ID was only incremented when a proposal was
+            // enacted. So a pending proposal may already use this ID, which is skipped.
+            0 => {
+                self.next_proposal_id = self
+                    .next_proposal_id
+                    .checked_add(1)
+                    .ok_or_else(|| MatchingEngineError::U64Overflow)?;
+                Ok(())
+            }
+            _ => err!(MatchingEngineError::UnsupportedAccountVersion),
+        }
+    }
 }
 
 impl admin::Ownable for Custodian {
@@ -47,6 +164,32 @@ impl admin::PendingOwner for Custodian {
     fn pending_owner_mut(&mut self) -> &mut Option<Pubkey> {
         &mut self.pending_owner
     }
+
+    fn pending_owner_window(&self) -> &admin::PendingOwnerWindow {
+        &self.pending_owner_window
+    }
+
+    fn pending_owner_window_mut(&mut self) -> &mut admin::PendingOwnerWindow {
+        &mut self.pending_owner_window
+    }
+}
+
+impl admin::MultisigOwnable for Custodian {
+    fn owner_set(&self) -> &admin::OwnerSet {
+        &self.owner_set
+    }
+
+    fn owner_set_mut(&mut self) -> &mut admin::OwnerSet {
+        &mut self.owner_set
+    }
+
+    fn pending_owner_set(&self) -> &Option<admin::OwnerSet> {
+        &self.pending_owner_set
+    }
+
+    fn pending_owner_set_mut(&mut self) -> &mut Option<admin::OwnerSet> {
+        &mut self.pending_owner_set
+    }
 }
 
 impl admin::OwnerAssistant for Custodian {
@@ -57,14 +200,40 @@ impl admin::OwnerAssistant for Custodian {
     fn owner_assistant_mut(&mut self) -> &mut Pubkey {
         &mut self.owner_assistant
     }
+
+    fn owner_assistant_rotation(&self) -> &Option<admin::OwnerAssistantRotation> {
+        &self.owner_assistant_rotation
+    }
+
+    fn owner_assistant_rotation_mut(&mut self) -> &mut Option<admin::OwnerAssistantRotation> {
+        &mut self.owner_assistant_rotation
+    }
 }
 
 #[cfg(test)]
 mod test {
+    #![allow(clippy::arithmetic_side_effects)]
+
+    use crate::state::Proposal;
+    use anchor_lang::Discriminator;
+    use common::admin::utils::migrate::upgrade_account_data;
     use solana_program::pubkey::Pubkey;
 
     use super::*;
 
+    /// Custodian layout before granular pause controls and versioning were introduced.
+    #[derive(AnchorSerialize)]
+    struct LegacyCustodian {
+        owner: Pubkey,
+        pending_owner: Option<Pubkey>,
+        paused: bool,
+        paused_set_by: Pubkey,
+        owner_assistant: Pubkey,
+        fee_recipient_token: Pubkey,
+        auction_config_id: u32,
+        next_proposal_id: u64,
+    }
+
     #[test]
     fn test_bump() {
         let (custodian, bump) =
@@ -76,4 +245,113 @@ mod test {
             "custodian mismatch",
         );
     }
+
+    #[test]
+    fn upgrade_legacy_custodian_with_stale_bytes() {
+        let mut legacy = LegacyCustodian {
+            owner: Pubkey::new_from_array([1; 32]),
+            pending_owner: Some(Pubkey::new_from_array([2; 32])),
+            paused: true,
+            paused_set_by: Pubkey::new_from_array([3; 32]),
+            owner_assistant: Pubkey::new_from_array([4; 32]),
+            fee_recipient_token: Pubkey::new_from_array([5; 32]),
+            auction_config_id: 6,
+            next_proposal_id: 7,
+        };
+
+        let mut data = Custodian::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        assert_eq!(data.len(), Custodian::UNVERSIONED_SIZE);
+
+        // Cancelling the ownership transfer request shortens the serialization, leaving stale bytes
+        // where the remaining pause flags are read.
+        legacy.pending_owner = None;
+        let mut writer = &mut data[8..];
+        legacy.serialize(&mut writer).unwrap();
+        assert!(data[data.len() - 32..].iter().any(|&b| b != 0));
+
+        // Reallocating zero-initializes the new bytes.
+        data.resize(Custodian::SIZE, 0);
+        assert!(upgrade_account_data::<Custodian>(
+            &mut data,
+            true,
+            error!(MatchingEngineError::UnsupportedAccountVersion)
+        )
+        .unwrap());
+
+        let upgraded = Custodian::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(upgraded.version, Custodian::VERSION);
+        assert_eq!(upgraded.owner, legacy.owner);
+        assert_eq!(upgraded.pending_owner, None);
+        assert_eq!(
+            upgraded.new_auctions_pause,
+            PauseFlag {
+                paused: true,
+                set_by: legacy.paused_set_by,
+            }
+        );
+        assert_eq!(upgraded.owner_assistant, legacy.owner_assistant);
+        assert_eq!(upgraded.fee_recipient_token, legacy.fee_recipient_token);
+        assert_eq!(upgraded.auction_config_id, 6);
+        assert_eq!(upgraded.next_proposal_id, 8);
+        assert_eq!(upgraded.offer_improvements_pause, PauseFlag::default());
+        assert_eq!(upgraded.execution_pause, PauseFlag::default());
+        assert_eq!(upgraded.local_fast_fills_pause, PauseFlag::default());
+        assert_eq!(upgraded.settlement_pause, PauseFlag::default());
+        assert_eq!(upgraded.owner_set, admin::OwnerSet::default());
+        assert_eq!(upgraded.pending_owner_set, None);
+        assert_eq!(upgraded.owner_assistant_rotation, None);
+
+        // Upgrading an account at the current version does nothing.
+        assert!(!upgrade_account_data::<Custodian>(
+            &mut data,
+            false,
+            error!(MatchingEngineError::UnsupportedAccountVersion)
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn upgrade_legacy_custodian_skips_pending_proposal_id() {
+        // Before versioning, a pending proposal used the next proposal ID, which was incremented
+        // only when the proposal was enacted.
+        let legacy = LegacyCustodian {
+            owner: Pubkey::new_from_array([1; 32]),
+            pending_owner: None,
+            paused: false,
+            paused_set_by: Pubkey::default(),
+            owner_assistant: Pubkey::new_from_array([2; 32]),
+            fee_recipient_token: Pubkey::new_from_array([3; 32]),
+            auction_config_id: 0,
+            next_proposal_id: 7,
+        };
+        let (pending_proposal, _) = Pubkey::find_program_address(
+            &[
+                Proposal::SEED_PREFIX,
+                &legacy.next_proposal_id.to_be_bytes(),
+            ],
+            &crate::id(),
+        );
+
+        let mut data = Custodian::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data.resize(Custodian::SIZE, 0);
+        assert!(upgrade_account_data::<Custodian>(
+            &mut data,
+            true,
+            error!(MatchingEngineError::UnsupportedAccountVersion)
+        )
+        .unwrap());
+
+        // The next proposal cannot collide with the pending proposal.
+        let upgraded = Custodian::try_deserialize(&mut &data[..]).unwrap();
+        let (next_proposal, _) = Pubkey::find_program_address(
+            &[
+                Proposal::SEED_PREFIX,
+                &upgraded.next_proposal_id.to_be_bytes(),
+            ],
+            &crate::id(),
+        );
+        assert_ne!(next_proposal, pending_proposal);
+    }
 }
@@ -39,6 +39,8 @@ pub struct FastFillSeeds {
     pub bump: u8,
 }
 
+/// NOTE: This account is not versioned (see [VersionedAccount](common::admin::utils::migrate::VersionedAccount)) because it is closed once the fast
+/// fill is redeemed.
 #[account]
 #[derive(Debug)]
 pub struct FastFill {
@@ -8,6 +8,8 @@ pub struct ReservedFastFillSequenceSeeds {
     pub bump: u8,
 }
 
+/// NOTE: This account is not versioned (see [VersionedAccount](common::admin::utils::migrate::VersionedAccount)) because it is closed once the fast
+/// order is executed or settled.
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct ReservedFastFillSequence {
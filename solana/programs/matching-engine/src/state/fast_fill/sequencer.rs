@@ -9,6 +9,9 @@ pub struct FastFillSequencerSeeds {
     pub bump: u8,
 }
 
+/// NOTE: This account is not versioned (see [VersionedAccount](common::admin::utils::migrate::VersionedAccount)). There is one sequencer per order
+/// sender, so they cannot all be migrated when the program is upgraded. This schema must not
+/// change.
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct FastFillSequencer {
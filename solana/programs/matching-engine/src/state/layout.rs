@@ -0,0 +1,427 @@
+//! Snapshot tests pinning the serialized layout of every account. If one of these fails, an
+//! account schema changed in a way that breaks existing accounts. Either revert the change or add a
+//! migration (see [VersionedAccount](common::admin::utils::migrate::VersionedAccount)) and update
+//! the snapshot with the layout printed by the failing test.
+
+#![allow(clippy::panic)]
+
+use super::*;
+use anchor_lang::prelude::*;
+use common::{
+    admin::{
+        utils::migrate::VersionedAccount, OwnerAssistantRotation, OwnerSet, PendingOwnerWindow,
+        RoleMember,
+    },
+    layout::AccountLayout,
+};
+
+fn assert_layout<T: AccountSerialize>(account: &T, layout: AccountLayout, snapshot: &str) {
+    if let Err(err) = layout.check(account, snapshot) {
+        panic!("{err}");
+    }
+}
+
+fn key(n: u8) -> Pubkey {
+    Pubkey::new_from_array([n; 32])
+}
+
+fn auction_info() -> AuctionInfo {
+    AuctionInfo {
+        config_id: 1,
+        custody_token_bump: 253,
+        vaa_sequence: 88,
+        source_chain: 6,
+        best_offer_token: key(3),
+        initial_offer_token: key(4),
+        start_slot: 1_000,
+        amount_in: 5_000_000,
+        security_deposit: 1_000_000,
+        offer_price: 250_000,
+        redeemer_message_len: 12,
+        destination_asset_info: None,
+    }
+}
+
+#[test]
+fn auction() {
+    let account = Auction {
+        bump: 254,
+        vaa_hash: [1; 32],
+        vaa_timestamp: 1_700_000_000,
+        target_protocol: MessageProtocol::Cctp { domain: 3 },
+        status: AuctionStatus::Completed {
+            slot: 69,
+            execute_penalty: Some(420),
+        },
+        prepared_by: key(2),
+        info: Some(auction_info()),
+    };
+    let layout = AccountLayout::new::<Auction>()
+        .field("bump", &account.bump)
+        .field("vaa_hash", &account.vaa_hash)
+        .field("vaa_timestamp", &account.vaa_timestamp)
+        .field("target_protocol", &account.target_protocol)
+        .field("status", &account.status)
+        .field("prepared_by", &account.prepared_by)
+        .field("info", &account.info);
+
+    assert_layout(&account, layout, include_str!("snapshots/auction.txt"));
+}
+
+#[test]
+fn auction_config() {
+    let account = AuctionConfig {
+        id: 2,
+        parameters: AuctionParameters {
+            user_penalty_reward_bps: 250_000,
+            initial_penalty_bps: 250_000,
+            duration: 2,
+            grace_period: 5,
+            penalty_period: 10,
+            min_offer_delta_bps: 20_000,
+            security_deposit_base: 4_200_000,
+            security_deposit_bps: 5_000,
+        },
+        version: AuctionConfig::VERSION,
+    };
+    let layout = AccountLayout::new::<AuctionConfig>()
+        .field("id", &account.id)
+        .field("parameters", &account.parameters)
+        .field("version", &account.version);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/auction_config.txt"),
+    );
+}
+
+#[test]
+fn auction_history() {
+    let account = AuctionHistory {
+        header: AuctionHistoryHeader {
+            id: 3,
+            min_timestamp: Some(1_700_000_000),
+            max_timestamp: Some(1_700_000_100),
+        },
+        data: vec![AuctionEntry {
+            vaa_hash: [5; 32],
+            vaa_timestamp: 1_700_000_050,
+            info: auction_info(),
+        }],
+    };
+    let layout = AccountLayout::new::<AuctionHistory>()
+        .field("header", &account.header)
+        .field("data", &account.data);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/auction_history.txt"),
+    );
+}
+
+#[test]
+fn custodian() {
+    let account = Custodian {
+        owner: key(1),
+        pending_owner: Some(key(2)),
+        new_auctions_pause: PauseFlag {
+            paused: true,
+            set_by: key(3),
+        },
+        owner_assistant: key(4),
+        fee_recipient_token: key(5),
+        auction_config_id: 2,
+        next_proposal_id: 7,
+        offer_improvements_pause: PauseFlag {
+            paused: false,
+            set_by: key(6),
+        },
+        execution_pause: PauseFlag {
+            paused: true,
+            set_by: key(7),
+        },
+        local_fast_fills_pause: PauseFlag {
+            paused: false,
+            set_by: key(8),
+        },
+        settlement_pause: PauseFlag {
+            paused: true,
+            set_by: key(9),
+        },
+        proposal_delays: ProposalDelays {
+            auction_parameters: 1,
+            router_endpoint: 2,
+            fee_recipient: 3,
+            owner_assistant: 4,
+            expiry_window: 5,
+        },
+        owner_set: OwnerSet::new(2, &[key(10), key(11)]).unwrap(),
+        pending_owner_set: None,
+        pending_owner_window: PendingOwnerWindow {
+            requested_slot: 100,
+            expiry_slot: 200,
+        },
+        owner_assistant_rotation: Some(OwnerAssistantRotation {
+            previous_owner_assistant: key(12),
+            expiry_slot: 300,
+        }),
+        version: Custodian::VERSION,
+    };
+    let layout = AccountLayout::new::<Custodian>()
+        .field("owner", &account.owner)
+        .field("pending_owner", &account.pending_owner)
+        .field("new_auctions_pause", &account.new_auctions_pause)
+        .field("owner_assistant", &account.owner_assistant)
+        .field("fee_recipient_token", &account.fee_recipient_token)
+        .field("auction_config_id", &account.auction_config_id)
+        .field("next_proposal_id", &account.next_proposal_id)
+        .field(
+            "offer_improvements_pause",
+            &account.offer_improvements_pause,
+        )
+        .field("execution_pause", &account.execution_pause)
+        .field("local_fast_fills_pause", &account.local_fast_fills_pause)
+        .field("settlement_pause", &account.settlement_pause)
+        .field("proposal_delays", &account.proposal_delays)
+        .field("owner_set", &account.owner_set)
+        .field("pending_owner_set", &account.pending_owner_set)
+        .field("pending_owner_window", &account.pending_owner_window)
+        .field(
+            "owner_assistant_rotation",
+            &account.owner_assistant_rotation,
+        )
+        .field("version", &account.version);
+
+    assert_layout(&account, layout, include_str!("snapshots/custodian.txt"));
+}
+
+#[test]
+fn fast_fill() {
+    let account = FastFill {
+        seeds: FastFillSeeds {
+            source_chain: 6,
+            order_sender: [1; 32],
+            sequence: 9,
+            bump: 250,
+        },
+        redeemed: true,
+        info: FastFillInfo {
+            prepared_by: key(2),
+            amount: 1_000_000,
+            redeemer: key(3),
+            timestamp: 1_700_000_000,
+        },
+        redeemer_message: b"hello".to_vec(),
+    };
+    let layout = AccountLayout::new::<FastFill>()
+        .field("seeds", &account.seeds)
+        .field("redeemed", &account.redeemed)
+        .field("info", &account.info)
+        .field("redeemer_message", &account.redeemer_message);
+
+    assert_layout(&account, layout, include_str!("snapshots/fast_fill.txt"));
+}
+
+#[test]
+fn fast_fill_sequencer() {
+    let account = FastFillSequencer {
+        seeds: FastFillSequencerSeeds {
+            source_chain: 6,
+            sender: [1; 32],
+            bump: 251,
+        },
+        next_sequence: 10,
+    };
+    let layout = AccountLayout::new::<FastFillSequencer>()
+        .field("seeds", &account.seeds)
+        .field("next_sequence", &account.next_sequence);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/fast_fill_sequencer.txt"),
+    );
+}
+
+#[test]
+fn reserved_fast_fill_sequence() {
+    let account = ReservedFastFillSequence {
+        seeds: ReservedFastFillSequenceSeeds {
+            fast_vaa_hash: [1; 32],
+            bump: 252,
+        },
+        beneficiary: key(2),
+        fast_fill_seeds: FastFillSeeds {
+            source_chain: 6,
+            order_sender: [3; 32],
+            sequence: 11,
+            bump: 250,
+        },
+    };
+    let layout = AccountLayout::new::<ReservedFastFillSequence>()
+        .field("seeds", &account.seeds)
+        .field("beneficiary", &account.beneficiary)
+        .field("fast_fill_seeds", &account.fast_fill_seeds);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/reserved_fast_fill_sequence.txt"),
+    );
+}
+
+#[test]
+fn prepared_order_response() {
+    let account = PreparedOrderResponse {
+        seeds: PreparedOrderResponseSeeds {
+            fast_vaa_hash: [1; 32],
+            bump: 249,
+        },
+        info: PreparedOrderResponseInfo {
+            prepared_by: key(2),
+            base_fee_token: key(3),
+            fast_vaa_timestamp: 1_700_000_000,
+            source_chain: 6,
+            base_fee: 1_000,
+            init_auction_fee: 500,
+            sender: [4; 32],
+            redeemer: [5; 32],
+            amount_in: 2_000_000,
+        },
+        to_endpoint: EndpointInfo {
+            chain: 2,
+            address: [6; 32],
+            mint_recipient: [7; 32],
+            protocol: MessageProtocol::Cctp { domain: 0 },
+        },
+        redeemer_message: b"hello".to_vec(),
+    };
+    let layout = AccountLayout::new::<PreparedOrderResponse>()
+        .field("seeds", &account.seeds)
+        .field("info", &account.info)
+        .field("to_endpoint", &account.to_endpoint)
+        .field("redeemer_message", &account.redeemer_message);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/prepared_order_response.txt"),
+    );
+}
+
+#[test]
+fn proposal() {
+    let account = Proposal {
+        id: 4,
+        bump: 248,
+        action: ProposalAction::UpdateFeeRecipient {
+            fee_recipient_token: key(1),
+        },
+        by: key(2),
+        owner: key(3),
+        slot_proposed_at: 100,
+        slot_enact_delay: 200,
+        slot_enacted_at: Some(300),
+    };
+    let layout = AccountLayout::new::<Proposal>()
+        .field("id", &account.id)
+        .field("bump", &account.bump)
+        .field("action", &account.action)
+        .field("by", &account.by)
+        .field("owner", &account.owner)
+        .field("slot_proposed_at", &account.slot_proposed_at)
+        .field("slot_enact_delay", &account.slot_enact_delay)
+        .field("slot_enacted_at", &account.slot_enacted_at);
+
+    assert_layout(&account, layout, include_str!("snapshots/proposal.txt"));
+}
+
+#[test]
+fn rate_limiter() {
+    let account = RateLimiter {
+        bump: 247,
+        chain: 2,
+        inbound: RateLimit {
+            config: Some(RateLimitConfig {
+                max_amount: 1_000_000,
+                window_slots: 100,
+            }),
+            window_start_slot: 10,
+            current_amount: 20,
+            previous_amount: 30,
+        },
+        outbound: RateLimit {
+            config: None,
+            window_start_slot: 40,
+            current_amount: 50,
+            previous_amount: 60,
+        },
+        version: RateLimiter::VERSION,
+    };
+    let layout = AccountLayout::new::<RateLimiter>()
+        .field("bump", &account.bump)
+        .field("chain", &account.chain)
+        .field("inbound", &account.inbound)
+        .field("outbound", &account.outbound)
+        .field("version", &account.version);
+
+    assert_layout(&account, layout, include_str!("snapshots/rate_limiter.txt"));
+}
+
+#[test]
+fn role_registry() {
+    let account = RoleRegistry {
+        bump: 246,
+        members: vec![RoleMember {
+            key: key(1),
+            roles: 3,
+        }],
+        version: RoleRegistry::VERSION,
+    };
+    let layout = AccountLayout::new::<RoleRegistry>()
+        .field("bump", &account.bump)
+        .field("members", &account.members)
+        .field("version", &account.version);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/role_registry.txt"),
+    );
+}
+
+#[test]
+fn router_endpoint() {
+    let account = RouterEndpoint {
+        bump: 245,
+        info: EndpointInfo {
+            chain: 2,
+            address: [1; 32],
+            mint_recipient: [2; 32],
+            protocol: MessageProtocol::Local { program_id: key(3) },
+        },
+        order_size_limits: OrderSizeLimits {
+            min_amount_in: 10,
+            max_fast_amount_in: Some(1_000_000),
+        },
+        pause: PauseFlag {
+            paused: true,
+            set_by: key(4),
+        },
+        version: RouterEndpoint::VERSION,
+    };
+    let layout = AccountLayout::new::<RouterEndpoint>()
+        .field("bump", &account.bump)
+        .field("info", &account.info)
+        .field("order_size_limits", &account.order_size_limits)
+        .field("pause", &account.pause)
+        .field("version", &account.version);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/router_endpoint.txt"),
+    );
+}
@@ -19,5 +19,14 @@ pub use prepared_order_response::*;
 mod proposal;
 pub use proposal::*;
 
+mod rate_limiter;
+pub use rate_limiter::*;
+
+mod role_registry;
+pub use role_registry::*;
+
 pub(crate) mod router_endpoint;
 pub use router_endpoint::*;
+
+#[cfg(test)]
+mod layout;
@@ -22,6 +22,10 @@ pub struct PreparedOrderResponseInfo {
     pub amount_in: u64,
 }
 
+/// NOTE: This account is not versioned (see
+/// [VersionedAccount](common::admin::utils::migrate::VersionedAccount)) because it is closed once the
+/// order is settled. Responses prepared before an upgrade must still be settled by the upgraded
+/// program, so this account's layout (including the embedded [EndpointInfo]) must not change.
 #[account]
 #[derive(Debug)]
 pub struct PreparedOrderResponse {
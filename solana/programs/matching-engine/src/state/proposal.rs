@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::AuctionParameters;
+use crate::{AddCctpRouterEndpointArgs, AuctionParameters};
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Eq, Copy)]
 pub enum ProposalAction {
@@ -9,8 +9,91 @@ pub enum ProposalAction {
         id: u32,
         parameters: AuctionParameters,
     },
+    AddCctpRouterEndpoint {
+        args: AddCctpRouterEndpointArgs,
+    },
+    UpdateCctpRouterEndpoint {
+        args: AddCctpRouterEndpointArgs,
+    },
+    AddLocalRouterEndpoint {
+        token_router_program: Pubkey,
+    },
+    UpdateLocalRouterEndpoint {
+        token_router_program: Pubkey,
+    },
+    DisableRouterEndpoint {
+        chain: u16,
+    },
+    UpdateFeeRecipient {
+        fee_recipient_token: Pubkey,
+    },
+    UpdateOwnerAssistant {
+        owner_assistant: Pubkey,
+    },
+    UpdateProposalDelays {
+        delays: ProposalDelays,
+    },
+}
+
+/// Number of slots a proposal must wait before it can be enacted, configured per action. If a delay
+/// is zero, the default delay is used instead.
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct ProposalDelays {
+    pub auction_parameters: u64,
+    pub router_endpoint: u64,
+    pub fee_recipient: u64,
+    pub owner_assistant: u64,
+
+    /// Number of slots after the enact delay expires within which a proposal must be enacted.
+    pub expiry_window: u64,
 }
 
+impl ProposalDelays {
+    /// Determine the number of slots a proposal can be enacted in once its enact delay expires.
+    pub fn expiry_window(&self, default_delay: u64) -> u64 {
+        if self.expiry_window == 0 {
+            default_delay
+        } else {
+            self.expiry_window
+        }
+    }
+
+    /// Determine the enact delay for a given action. Updating the delays themselves requires the
+    /// longest configured delay so that no action's delay can be circumvented.
+    pub fn enact_delay(&self, action: &ProposalAction, default_delay: u64) -> u64 {
+        let or_default = |delay: u64| if delay == 0 { default_delay } else { delay };
+
+        match action {
+            ProposalAction::None => default_delay,
+            ProposalAction::UpdateAuctionParameters { .. } => or_default(self.auction_parameters),
+            ProposalAction::AddCctpRouterEndpoint { .. }
+            | ProposalAction::UpdateCctpRouterEndpoint { .. }
+            | ProposalAction::AddLocalRouterEndpoint { .. }
+            | ProposalAction::UpdateLocalRouterEndpoint { .. }
+            | ProposalAction::DisableRouterEndpoint { .. } => or_default(self.router_endpoint),
+            ProposalAction::UpdateFeeRecipient { .. } => or_default(self.fee_recipient),
+            ProposalAction::UpdateOwnerAssistant { .. } => or_default(self.owner_assistant),
+            ProposalAction::UpdateProposalDelays { .. } => [
+                self.auction_parameters,
+                self.router_endpoint,
+                self.fee_recipient,
+                self.owner_assistant,
+            ]
+            .into_iter()
+            .map(or_default)
+            .max()
+            .unwrap_or_else(|| default_delay),
+        }
+    }
+}
+
+/// NOTE: This account is not versioned (see
+/// [VersionedAccount](common::admin::utils::migrate::VersionedAccount)) because it is only read while
+/// it is pending, which lasts until it expires. A pending proposal can be closed by its proposer or
+/// the owner (or owner assistant). An enacted proposal is not closed, but it keeps its
+/// `slot_enacted_at` so it cannot be enacted again.
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct Proposal {
@@ -29,3 +112,65 @@ pub struct Proposal {
 impl Proposal {
     pub const SEED_PREFIX: &'static [u8] = b"proposal";
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DEFAULT_DELAY: u64 = 69;
+
+    #[test]
+    fn zero_delay_uses_default() {
+        let delays = ProposalDelays {
+            router_endpoint: 420,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            delays.enact_delay(
+                &ProposalAction::DisableRouterEndpoint { chain: 2 },
+                DEFAULT_DELAY
+            ),
+            420
+        );
+        assert_eq!(
+            delays.enact_delay(
+                &ProposalAction::UpdateFeeRecipient {
+                    fee_recipient_token: Default::default()
+                },
+                DEFAULT_DELAY
+            ),
+            DEFAULT_DELAY
+        );
+    }
+
+    #[test]
+    fn update_proposal_delays_uses_longest_delay() {
+        let delays = ProposalDelays {
+            auction_parameters: 1,
+            router_endpoint: 420,
+            fee_recipient: 2,
+            owner_assistant: 3,
+            expiry_window: 69_420,
+        };
+
+        assert_eq!(
+            delays.enact_delay(
+                &ProposalAction::UpdateProposalDelays {
+                    delays: Default::default()
+                },
+                DEFAULT_DELAY
+            ),
+            420
+        );
+        assert_eq!(
+            ProposalDelays::default().enact_delay(
+                &ProposalAction::UpdateProposalDelays {
+                    delays: Default::default()
+                },
+                DEFAULT_DELAY
+            ),
+            DEFAULT_DELAY
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use crate::error::MatchingEngineError;
+use anchor_lang::prelude::*;
+use common::admin::utils::migrate::VersionedAccount;
+
+/// Rate limit parameters set by the owner.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct RateLimitConfig {
+    /// Maximum amount allowed to be auctioned within a rolling window.
+    pub max_amount: u64,
+
+    /// Length of the rolling window in slots. Cannot be zero.
+    pub window_slots: u64,
+}
+
+/// Usage tracked for one direction of a router endpoint. The rolling window is approximated by
+/// weighting the previous window's usage by how much of it still overlaps with the current slot.
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct RateLimit {
+    /// If not set, there is no limit for this direction.
+    pub config: Option<RateLimitConfig>,
+
+    /// Slot at which the current window started.
+    pub window_start_slot: u64,
+
+    /// Amount consumed in the current window.
+    pub current_amount: u64,
+
+    /// Amount consumed in the window before the current one.
+    pub previous_amount: u64,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Inbound and outbound rate limits for a specific router endpoint.
+pub struct RateLimiter {
+    pub bump: u8,
+
+    /// Chain of the router endpoint these limits apply to.
+    pub chain: u16,
+
+    /// Limits fast orders originating from this endpoint's chain.
+    pub inbound: RateLimit,
+
+    /// Limits fast orders destined for this endpoint's chain.
+    pub outbound: RateLimit,
+
+    /// Schema version of this account. New fields must be added after this one.
+    pub version: u8,
+}
+
+impl RateLimiter {
+    pub const SEED_PREFIX: &'static [u8] = b"rate-limiter";
+}
+
+impl VersionedAccount for RateLimiter {
+    const VERSION: u8 = 1;
+    const SIZE: usize = 8 + Self::INIT_SPACE;
+    // Rate limiters were introduced with versioning.
+    const UNVERSIONED_SIZE: usize = 0;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+
+    fn upgrade_from(&mut self, _version: u8) -> Result<()> {
+        // There is no previous version to upgrade from.
+        err!(MatchingEngineError::UnsupportedAccountVersion)
+    }
+}
@@ -0,0 +1,50 @@
+use crate::error::MatchingEngineError;
+use anchor_lang::prelude::*;
+use common::admin::{self, utils::migrate::VersionedAccount, RoleMember, MAX_ROLE_MEMBERS};
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Roles granted by the owner to keys other than the owner and owner assistant.
+pub struct RoleRegistry {
+    pub bump: u8,
+
+    #[max_len(MAX_ROLE_MEMBERS)]
+    pub members: Vec<RoleMember>,
+
+    /// Schema version of this account. New fields must be added after this one.
+    pub version: u8,
+}
+
+impl RoleRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"roles";
+}
+
+impl admin::HasRoles for RoleRegistry {
+    fn role_members(&self) -> &Vec<RoleMember> {
+        &self.members
+    }
+
+    fn role_members_mut(&mut self) -> &mut Vec<RoleMember> {
+        &mut self.members
+    }
+}
+
+impl VersionedAccount for RoleRegistry {
+    const VERSION: u8 = 1;
+    const SIZE: usize = 8 + Self::INIT_SPACE;
+    // Role registries were introduced with versioning.
+    const UNVERSIONED_SIZE: usize = 0;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+
+    fn upgrade_from(&mut self, _version: u8) -> Result<()> {
+        // There is no previous version to upgrade from.
+        err!(MatchingEngineError::UnsupportedAccountVersion)
+    }
+}
@@ -1,4 +1,7 @@
+use super::PauseFlag;
+use crate::error::MatchingEngineError;
 use anchor_lang::prelude::*;
+use common::admin::utils::migrate::VersionedAccount;
 
 /// Protocol used to transfer assets.
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Copy)]
@@ -26,6 +29,8 @@ impl std::fmt::Display for MessageProtocol {
     }
 }
 
+/// NOTE: This struct is embedded in prepared order responses, which are not versioned. So its
+/// layout must not change. Add new router endpoint fields to [RouterEndpoint] instead.
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
 pub struct EndpointInfo {
     /// Emitter chain. Cannot equal `1` (Solana's Chain ID).
@@ -42,12 +47,34 @@ pub struct EndpointInfo {
     pub protocol: MessageProtocol,
 }
 
+#[derive(
+    Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct OrderSizeLimits {
+    /// Minimum order amount allowed to be auctioned. Orders below this amount are rejected.
+    pub min_amount_in: u64,
+
+    /// Maximum order amount allowed to be auctioned. Orders above this amount can only be settled
+    /// via the slow (finalized) path. If not set, there is no maximum.
+    pub max_fast_amount_in: Option<u64>,
+}
+
 #[account]
 #[derive(Debug, InitSpace)]
 /// Foreign emitter account data.
 pub struct RouterEndpoint {
     pub bump: u8,
     pub info: EndpointInfo,
+
+    /// Order sizes allowed to be auctioned to or from this endpoint.
+    pub order_size_limits: OrderSizeLimits,
+
+    /// Indicates whether fast orders to or from this endpoint are paused. Orders already in flight
+    /// on other routes are unaffected.
+    pub pause: PauseFlag,
+
+    /// Schema version of this account. New fields must be added after this one.
+    pub version: u8,
 }
 
 impl std::ops::Deref for RouterEndpoint {
@@ -61,3 +88,159 @@ impl std::ops::Deref for RouterEndpoint {
 impl RouterEndpoint {
     pub const SEED_PREFIX: &'static [u8] = b"endpoint";
 }
+
+impl VersionedAccount for RouterEndpoint {
+    const VERSION: u8 = 1;
+    const SIZE: usize = 8 + Self::INIT_SPACE;
+    const UNVERSIONED_SIZE: usize = 8 // discriminator
+        + 1 // bump
+        + 2 // chain
+        + 32 // address
+        + 32 // mint_recipient
+        + MessageProtocol::INIT_SPACE // protocol
+    ;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+
+    fn read_version(buf: &mut &[u8], unversioned: bool) -> Result<u8> {
+        if unversioned {
+            <(u8, u16, [u8; 32], [u8; 32], MessageProtocol)>::deserialize(buf)?;
+            Ok(0)
+        } else {
+            Self::deserialize(buf)
+                .map(|endpoint| endpoint.version)
+                .map_err(Into::into)
+        }
+    }
+
+    fn upgrade_from(&mut self, version: u8) -> Result<()> {
+        match version {
+            // Accounts created before versioning have no other fields to set.
+            0 => Ok(()),
+            _ => err!(MatchingEngineError::UnsupportedAccountVersion),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::arithmetic_side_effects)]
+
+    use super::*;
+    use anchor_lang::Discriminator;
+    use common::admin::utils::migrate::upgrade_account_data;
+    use std::io::Write;
+
+    /// Router endpoint layout before order size limits, pause controls and versioning were
+    /// introduced.
+    #[derive(AnchorSerialize)]
+    struct LegacyRouterEndpoint {
+        bump: u8,
+        chain: u16,
+        address: [u8; 32],
+        mint_recipient: [u8; 32],
+        protocol: MessageProtocol,
+    }
+
+    fn legacy_router_endpoint(protocol: MessageProtocol) -> LegacyRouterEndpoint {
+        LegacyRouterEndpoint {
+            bump: 254,
+            chain: 2,
+            address: [1; 32],
+            mint_recipient: [2; 32],
+            protocol,
+        }
+    }
+
+    /// Write a legacy router endpoint over existing account data without zeroing the bytes after
+    /// it, which is how Anchor writes accounts.
+    fn write_legacy(data: &mut [u8], legacy: &LegacyRouterEndpoint) {
+        let mut writer = data;
+        writer.write_all(&RouterEndpoint::DISCRIMINATOR).unwrap();
+        legacy.serialize(&mut writer).unwrap();
+    }
+
+    fn upgrade(data: &mut [u8], unversioned: bool) -> Result<bool> {
+        upgrade_account_data::<RouterEndpoint>(
+            data,
+            unversioned,
+            error!(MatchingEngineError::UnsupportedAccountVersion),
+        )
+    }
+
+    #[test]
+    fn upgrade_unversioned_router_endpoint() {
+        let mut data = vec![0; RouterEndpoint::UNVERSIONED_SIZE];
+        write_legacy(
+            &mut data,
+            &legacy_router_endpoint(MessageProtocol::Cctp { domain: 0 }),
+        );
+
+        // Reallocating zero-initializes the new bytes.
+        data.resize(RouterEndpoint::SIZE, 0);
+        assert!(upgrade(&mut data, true).unwrap());
+
+        let upgraded = RouterEndpoint::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(upgraded.version, RouterEndpoint::VERSION);
+        assert_eq!(upgraded.bump, 254);
+        assert_eq!(upgraded.chain, 2);
+        assert_eq!(upgraded.address, [1; 32]);
+        assert_eq!(upgraded.mint_recipient, [2; 32]);
+        assert_eq!(upgraded.protocol, MessageProtocol::Cctp { domain: 0 });
+        assert_eq!(upgraded.order_size_limits, OrderSizeLimits::default());
+        assert_eq!(upgraded.pause, PauseFlag::default());
+
+        // Upgrading an account at the current version does nothing.
+        assert!(!upgrade(&mut data, false).unwrap());
+    }
+
+    #[test]
+    fn upgrade_unversioned_router_endpoint_with_stale_bytes() {
+        let mut data = vec![0; RouterEndpoint::UNVERSIONED_SIZE];
+        write_legacy(
+            &mut data,
+            &legacy_router_endpoint(MessageProtocol::Local {
+                program_id: Pubkey::new_from_array([4; 32]),
+            }),
+        );
+
+        // Disabling the endpoint shortened its serialization, leaving stale bytes where the order
+        // size limits are read.
+        let disabled = legacy_router_endpoint(MessageProtocol::None);
+        write_legacy(&mut data, &disabled);
+        let serialized_len = 8 + disabled.try_to_vec().unwrap().len();
+        assert_ne!(data[serialized_len], 0);
+
+        data.resize(RouterEndpoint::SIZE, 0);
+        assert!(upgrade(&mut data, true).unwrap());
+
+        let upgraded = RouterEndpoint::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(upgraded.version, RouterEndpoint::VERSION);
+        assert_eq!(upgraded.protocol, MessageProtocol::None);
+        assert_eq!(upgraded.order_size_limits, OrderSizeLimits::default());
+        assert_eq!(upgraded.pause, PauseFlag::default());
+
+        // Bytes after the version are zeroed.
+        let upgraded_len = 8 + upgraded.try_to_vec().unwrap().len();
+        assert!(data[upgraded_len..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn cannot_upgrade_future_version() {
+        let mut data = vec![0; RouterEndpoint::UNVERSIONED_SIZE];
+        write_legacy(&mut data, &legacy_router_endpoint(MessageProtocol::None));
+        data.resize(RouterEndpoint::SIZE, 0);
+
+        let mut endpoint = RouterEndpoint::try_deserialize(&mut &data[..]).unwrap();
+        endpoint.version = RouterEndpoint::VERSION + 1;
+        endpoint.try_serialize(&mut &mut data[..]).unwrap();
+
+        assert!(upgrade(&mut data, false).is_err());
+    }
+}
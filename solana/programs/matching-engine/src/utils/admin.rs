@@ -4,9 +4,11 @@ use crate::{
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
-use common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN;
+use common::{
+    admin::utils::migrate::VersionedAccount, wormhole_cctp_solana::wormhole::SOLANA_CHAIN,
+};
 
-#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub struct AddCctpRouterEndpointArgs {
     pub chain: u16,
     pub cctp_domain: u32,
@@ -14,11 +16,40 @@ pub struct AddCctpRouterEndpointArgs {
     pub mint_recipient: Option<[u8; 32]>,
 }
 
+pub(crate) fn require_valid_cctp_router_endpoint_args(
+    args: &AddCctpRouterEndpointArgs,
+) -> Result<()> {
+    let AddCctpRouterEndpointArgs {
+        chain,
+        cctp_domain: _,
+        address,
+        mint_recipient,
+    } = args;
+
+    require!(
+        *chain != 0 && *chain != SOLANA_CHAIN,
+        MatchingEngineError::ChainNotAllowed
+    );
+
+    require!(*address != [0; 32], MatchingEngineError::InvalidEndpoint);
+
+    if let Some(mint_recipient) = mint_recipient {
+        require!(
+            *mint_recipient != [0; 32],
+            MatchingEngineError::InvalidMintRecipient
+        );
+    }
+
+    Ok(())
+}
+
 pub(crate) fn handle_add_cctp_router_endpoint(
     router_endpoint: &mut Account<RouterEndpoint>,
     args: AddCctpRouterEndpointArgs,
     router_endpoint_bump: Option<u8>,
 ) -> Result<()> {
+    require_valid_cctp_router_endpoint_args(&args)?;
+
     let bump = router_endpoint_bump.unwrap_or_else(|| router_endpoint.bump);
 
     let AddCctpRouterEndpointArgs {
@@ -28,23 +59,11 @@ pub(crate) fn handle_add_cctp_router_endpoint(
         mint_recipient,
     } = args;
 
-    require!(
-        chain != 0 && chain != SOLANA_CHAIN,
-        MatchingEngineError::ChainNotAllowed
-    );
+    let mint_recipient = mint_recipient.unwrap_or_else(|| address);
 
-    require!(address != [0; 32], MatchingEngineError::InvalidEndpoint);
-
-    let mint_recipient = match mint_recipient {
-        Some(mint_recipient) => {
-            require!(
-                mint_recipient != [0; 32],
-                MatchingEngineError::InvalidMintRecipient
-            );
-            mint_recipient
-        }
-        None => address,
-    };
+    // Preserve order size limits and pause state if this router endpoint is being updated.
+    let order_size_limits = router_endpoint.order_size_limits;
+    let pause = router_endpoint.pause;
 
     router_endpoint.set_inner(RouterEndpoint {
         bump,
@@ -54,6 +73,9 @@ pub(crate) fn handle_add_cctp_router_endpoint(
             mint_recipient,
             protocol: MessageProtocol::Cctp { domain },
         },
+        order_size_limits,
+        pause,
+        version: RouterEndpoint::VERSION,
     });
 
     // Done.
@@ -69,6 +91,10 @@ pub(crate) fn handle_add_local_router_endpoint(
 ) -> Result<()> {
     let bump = router_endpoint_bump.unwrap_or_else(|| router_endpoint.bump);
 
+    // Preserve order size limits and pause state if this router endpoint is being updated.
+    let order_size_limits = router_endpoint.order_size_limits;
+    let pause = router_endpoint.pause;
+
     router_endpoint.set_inner(RouterEndpoint {
         bump,
         info: EndpointInfo {
@@ -79,7 +105,29 @@ pub(crate) fn handle_add_local_router_endpoint(
                 program_id: token_router_program.key(),
             },
         },
+        order_size_limits,
+        pause,
+        version: RouterEndpoint::VERSION,
     });
 
     Ok(())
 }
+
+/// Default number of slots used for a proposal's enact delay and expiry window when the custodian
+/// does not configure one.
+pub(crate) fn default_proposal_delay(epoch_schedule: &EpochSchedule) -> u64 {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "integration-test")] {
+            let _ = epoch_schedule;
+            // Arbitrary set for fast testing.
+            8
+        } else if #[cfg(feature = "testnet")] {
+            let _ = epoch_schedule;
+            // Arbitrary set to roughly 10 seconds (10 seconds / 0.4 seconds per slot) for
+            // faster testing.
+            25
+        } else {
+            epoch_schedule.slots_per_epoch
+        }
+    }
+}
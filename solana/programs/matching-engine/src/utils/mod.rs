@@ -2,7 +2,12 @@ pub mod admin;
 
 pub mod auction;
 
-use crate::{error::MatchingEngineError, state::RouterEndpoint};
+pub mod rate_limit;
+
+use crate::{
+    error::MatchingEngineError,
+    state::{OrderSizeLimits, RouterEndpoint},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use common::wormhole_cctp_solana::wormhole::{VaaAccount, SOLANA_CHAIN};
@@ -42,6 +47,22 @@ pub fn require_local_endpoint(endpoint: &RouterEndpoint) -> Result<bool> {
     Ok(true)
 }
 
+pub fn require_valid_fast_order_amount(limits: &OrderSizeLimits, amount_in: u64) -> Result<()> {
+    require!(
+        amount_in >= limits.min_amount_in,
+        MatchingEngineError::AmountInTooSmall
+    );
+
+    if let Some(max_fast_amount_in) = limits.max_fast_amount_in {
+        require!(
+            amount_in <= max_fast_amount_in,
+            MatchingEngineError::FastAmountInTooLarge
+        );
+    }
+
+    Ok(())
+}
+
 pub fn checked_deserialize_token_account(
     acc_info: &AccountInfo,
     expected_mint: &Pubkey,
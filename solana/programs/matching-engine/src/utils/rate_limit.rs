@@ -0,0 +1,277 @@
+use crate::{
+    error::MatchingEngineError,
+    state::{RateLimit, RateLimitConfig, RateLimiter},
+};
+use anchor_lang::prelude::*;
+
+pub fn require_valid_config(config: &RateLimitConfig) -> Result<()> {
+    require!(
+        config.window_slots > 0,
+        MatchingEngineError::ZeroRateLimitWindow
+    );
+
+    Ok(())
+}
+
+/// Replace the rate limit config. Usage is only carried over if the window length is unchanged.
+pub fn set_config(limit: &mut RateLimit, config: Option<RateLimitConfig>) {
+    let same_window = matches!(
+        (limit.config, config),
+        (Some(prev), Some(next)) if prev.window_slots == next.window_slots
+    );
+
+    if same_window {
+        limit.config = config;
+    } else {
+        *limit = RateLimit {
+            config,
+            ..Default::default()
+        };
+    }
+}
+
+/// Compute the amount consumed within the rolling window ending at the current slot. Returns zero
+/// if there is no limit configured.
+pub fn compute_window_usage(limit: &RateLimit, current_slot: u64) -> u64 {
+    match limit.config {
+        Some(config) => {
+            let rolled = roll_window(limit, config.window_slots, current_slot);
+
+            // NOTE: The current slot is always within the rolled window, so the remaining slots
+            // are at most the window length.
+            let remaining_slots = config
+                .window_slots
+                .saturating_sub(current_slot.saturating_sub(rolled.window_start_slot));
+            let weighted_previous = u128::from(rolled.previous_amount)
+                .saturating_mul(remaining_slots.into())
+                .checked_div(config.window_slots.into())
+                .unwrap_or_default();
+
+            // NOTE: The weighted amount never exceeds the previous amount, so it fits in u64.
+            rolled.current_amount.saturating_add(
+                u64::try_from(weighted_previous).unwrap_or_else(|_| rolled.previous_amount),
+            )
+        }
+        None => 0,
+    }
+}
+
+/// Attempt to consume the amount from the rate limit. Returns false (without modifying the limit)
+/// if the amount would exceed the limit within the rolling window.
+pub fn try_consume(limit: &mut RateLimit, amount: u64, current_slot: u64) -> bool {
+    match limit.config {
+        Some(config) => {
+            if compute_window_usage(limit, current_slot).saturating_add(amount) > config.max_amount
+            {
+                false
+            } else {
+                let mut rolled = roll_window(limit, config.window_slots, current_slot);
+                rolled.current_amount = rolled.current_amount.saturating_add(amount);
+                *limit = rolled;
+
+                true
+            }
+        }
+        None => true,
+    }
+}
+
+/// Attempt to consume the amount from the limit selected from a rate limiter account. Router
+/// endpoints whose rate limiter has not been created have no limits. Returns false (without
+/// modifying the account) if the amount would exceed the limit within the rolling window.
+pub fn try_consume_account(
+    rate_limiter: &AccountInfo,
+    select_limit: impl FnOnce(&mut RateLimiter) -> &mut RateLimit,
+    amount: u64,
+    current_slot: u64,
+) -> Result<bool> {
+    if rate_limiter.data_is_empty() {
+        return Ok(true);
+    }
+
+    require_keys_eq!(
+        *rate_limiter.owner,
+        crate::ID,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    let mut data = rate_limiter.try_borrow_mut_data()?;
+    let mut account = RateLimiter::try_deserialize(&mut &data[..])?;
+    if !try_consume(select_limit(&mut account), amount, current_slot) {
+        return Ok(false);
+    }
+
+    account.try_serialize(&mut &mut data[..])?;
+    Ok(true)
+}
+
+fn roll_window(limit: &RateLimit, window_slots: u64, current_slot: u64) -> RateLimit {
+    let elapsed_windows = current_slot
+        .saturating_sub(limit.window_start_slot)
+        .checked_div(window_slots)
+        .unwrap_or_default();
+
+    match elapsed_windows {
+        0 => *limit,
+        1 => RateLimit {
+            window_start_slot: limit.window_start_slot.saturating_add(window_slots),
+            current_amount: 0,
+            previous_amount: limit.current_amount,
+            ..*limit
+        },
+        _ => RateLimit {
+            window_start_slot: limit
+                .window_start_slot
+                .saturating_add(elapsed_windows.saturating_mul(window_slots)),
+            current_amount: 0,
+            previous_amount: 0,
+            ..*limit
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::arithmetic_side_effects)]
+
+    use super::*;
+
+    const WINDOW_SLOTS: u64 = 100;
+    const MAX_AMOUNT: u64 = 1_000_000;
+
+    #[test]
+    fn no_limit() {
+        let mut limit = RateLimit::default();
+
+        assert!(try_consume(&mut limit, u64::MAX, 69));
+        assert_eq!(limit, RateLimit::default());
+        assert_eq!(compute_window_usage(&limit, 69), 0);
+    }
+
+    #[test]
+    fn consume_within_window() {
+        let mut limit = set_up();
+
+        assert!(try_consume(&mut limit, 400_000, 0));
+        assert!(try_consume(&mut limit, 600_000, 99));
+        assert!(!try_consume(&mut limit, 1, 99));
+        assert_eq!(compute_window_usage(&limit, 99), MAX_AMOUNT);
+    }
+
+    #[test]
+    fn amount_exceeds_max() {
+        let mut limit = set_up();
+
+        assert!(!try_consume(&mut limit, MAX_AMOUNT + 1, 0));
+        assert_eq!(limit, set_up());
+    }
+
+    #[test]
+    fn previous_window_is_weighted() {
+        let mut limit = set_up();
+        assert!(try_consume(&mut limit, MAX_AMOUNT, 50));
+
+        // A quarter of the way into the next window, three quarters of the previous window's usage
+        // still counts.
+        assert_eq!(compute_window_usage(&limit, 125), 750_000);
+        assert!(!try_consume(&mut limit, 250_001, 125));
+        assert!(try_consume(&mut limit, 250_000, 125));
+        assert_eq!(limit.window_start_slot, WINDOW_SLOTS);
+        assert_eq!(limit.previous_amount, MAX_AMOUNT);
+        assert_eq!(limit.current_amount, 250_000);
+    }
+
+    #[test]
+    fn usage_expires_after_two_windows() {
+        let mut limit = set_up();
+        assert!(try_consume(&mut limit, MAX_AMOUNT, 0));
+
+        assert_eq!(compute_window_usage(&limit, 2 * WINDOW_SLOTS), 0);
+        assert!(try_consume(&mut limit, MAX_AMOUNT, 2 * WINDOW_SLOTS + 1));
+        assert_eq!(limit.window_start_slot, 2 * WINDOW_SLOTS);
+        assert_eq!(limit.previous_amount, 0);
+    }
+
+    #[test]
+    fn set_config_keeps_usage_for_same_window() {
+        let mut limit = set_up();
+        assert!(try_consume(&mut limit, 500_000, 0));
+
+        set_config(
+            &mut limit,
+            Some(RateLimitConfig {
+                max_amount: 2 * MAX_AMOUNT,
+                window_slots: WINDOW_SLOTS,
+            }),
+        );
+        assert_eq!(limit.current_amount, 500_000);
+
+        set_config(
+            &mut limit,
+            Some(RateLimitConfig {
+                max_amount: 2 * MAX_AMOUNT,
+                window_slots: 2 * WINDOW_SLOTS,
+            }),
+        );
+        assert_eq!(limit.current_amount, 0);
+    }
+
+    #[test]
+    fn consume_from_rate_limiter_account() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+
+        // Rate limiter has not been created.
+        let mut data = Vec::new();
+        let system_program = anchor_lang::system_program::ID;
+        let acc_info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &system_program,
+            false,
+            0,
+        );
+        assert!(try_consume_account(&acc_info, |r| &mut r.inbound, u64::MAX, 0).unwrap());
+
+        let mut data = Vec::new();
+        RateLimiter {
+            bump: 255,
+            chain: 2,
+            inbound: set_up(),
+            outbound: RateLimit::default(),
+            version: 1,
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        let acc_info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+        assert!(try_consume_account(&acc_info, |r| &mut r.inbound, 600_000, 0).unwrap());
+        assert!(!try_consume_account(&acc_info, |r| &mut r.inbound, 600_000, 0).unwrap());
+        assert!(try_consume_account(&acc_info, |r| &mut r.outbound, u64::MAX, 0).unwrap());
+
+        let rate_limiter = RateLimiter::try_deserialize(&mut &acc_info.data.borrow()[..]).unwrap();
+        assert_eq!(rate_limiter.inbound.current_amount, 600_000);
+        assert_eq!(rate_limiter.outbound, RateLimit::default());
+    }
+
+    fn set_up() -> RateLimit {
+        RateLimit {
+            config: Some(RateLimitConfig {
+                max_amount: MAX_AMOUNT,
+                window_slots: WINDOW_SLOTS,
+            }),
+            ..Default::default()
+        }
+    }
+}
@@ -1,29 +1,15 @@
 use std::ops::Deref;
 
-use crate::{error::TokenRouterError, state::Custodian};
+use crate::{
+    error::TokenRouterError,
+    state::{Custodian, RoleRegistry},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
-use common::{
-    admin::utils::{assistant::only_authorized, ownable::only_owner},
-    messages::raw::LiquidityLayerMessage,
-    wormhole_cctp_solana::wormhole::VaaAccount,
-};
+use common::{messages::raw::LiquidityLayerMessage, wormhole_cctp_solana::wormhole::VaaAccount};
 use matching_engine::state::RouterEndpoint;
 
-#[derive(Accounts)]
-pub struct Usdc<'info> {
-    /// CHECK: This address must equal [USDC_MINT](common::USDC_MINT).
-    #[account(address = common::USDC_MINT)]
-    pub mint: UncheckedAccount<'info>,
-}
-
-impl<'info> Deref for Usdc<'info> {
-    type Target = UncheckedAccount<'info>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.mint
-    }
-}
+common::admin_composites!(Custodian, RoleRegistry, TokenRouterError);
 
 /// Mint recipient token account, which is encoded as the mint recipient in the CCTP message.
 /// The CCTP Token Messenger Minter program will transfer the amount encoded in the CCTP message
@@ -81,89 +67,6 @@ impl<'info> Deref for LiquidityLayerVaa<'info> {
     }
 }
 
-#[derive(Accounts)]
-pub struct CheckedCustodian<'info> {
-    #[account(
-        seeds = [Custodian::SEED_PREFIX],
-        bump = Custodian::BUMP,
-    )]
-    pub custodian: Box<Account<'info, Custodian>>,
-}
-
-impl<'info> Deref for CheckedCustodian<'info> {
-    type Target = Account<'info, Custodian>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.custodian
-    }
-}
-
-#[derive(Accounts)]
-pub struct OwnerOnly<'info> {
-    #[account(
-        constraint = only_owner(
-            &custodian,
-            &owner,
-            error!(TokenRouterError::OwnerOnly)
-        )?
-    )]
-    pub owner: Signer<'info>,
-
-    pub custodian: CheckedCustodian<'info>,
-}
-
-#[derive(Accounts)]
-pub struct OwnerOnlyMut<'info> {
-    #[account(
-        constraint = only_owner(
-            &custodian,
-            &owner,
-            error!(TokenRouterError::OwnerOnly)
-        )?
-    )]
-    pub owner: Signer<'info>,
-
-    #[account(
-        mut,
-        seeds = [Custodian::SEED_PREFIX],
-        bump = Custodian::BUMP,
-    )]
-    pub custodian: Box<Account<'info, Custodian>>,
-}
-
-#[derive(Accounts)]
-pub struct Admin<'info> {
-    #[account(
-        constraint = only_authorized(
-            &custodian,
-            &owner_or_assistant,
-            error!(TokenRouterError::OwnerOrAssistantOnly)
-        )?
-    )]
-    pub owner_or_assistant: Signer<'info>,
-
-    pub custodian: CheckedCustodian<'info>,
-}
-
-#[derive(Accounts)]
-pub struct AdminMut<'info> {
-    #[account(
-        constraint = only_authorized(
-            &custodian,
-            &owner_or_assistant,
-            error!(TokenRouterError::OwnerOrAssistantOnly)
-        )?
-    )]
-    pub owner_or_assistant: Signer<'info>,
-
-    #[account(
-        mut,
-        seeds = [Custodian::SEED_PREFIX],
-        bump = Custodian::BUMP,
-    )]
-    pub custodian: Box<Account<'info, Custodian>>,
-}
-
 /// Registered router endpoint representing a foreign Token Router. This account may have a CCTP
 /// domain encoded if this route is CCTP-enabled. For this instruction, it is required that
 /// [RouterEndpoint::cctp_domain] is `Some(value)`.
@@ -2,6 +2,8 @@
 pub enum TokenRouterError {
     OwnerOnly = 0x2,
     OwnerOrAssistantOnly = 0x4,
+    MissingRole = 0x6,
+    RoleRegistryFull = 0x8,
 
     U64Overflow = 0x10,
 
@@ -21,6 +23,8 @@ pub enum TokenRouterError {
 
     AssistantZeroPubkey = 0x100,
     ImmutableProgram = 0x102,
+    #[msg("Value exceeds 10000")]
+    IntegratorFeeBpsTooLarge = 0x104,
 
     InvalidNewOwner = 0x202,
     AlreadyOwner = 0x204,
@@ -28,9 +32,48 @@ pub enum TokenRouterError {
     NotPendingOwner = 0x208,
     EitherSenderOrProgramTransferAuthority = 0x20a,
     DelegatedAmountMismatch = 0x20c,
+    InvalidOwnerSet = 0x20e,
+    OwnerSetNotApproved = 0x210,
+    PendingOwnerExpired = 0x212,
+    NoOwnerAssistantRotation = 0x214,
 
     InsufficientAmount = 0x400,
     MinAmountOutTooHigh = 0x402,
     InvalidRedeemer = 0x404,
     PreparedFillTooLarge = 0x406,
+    InvalidOrderType = 0x408,
+    FastOrderFeesTooHigh = 0x40a,
+    FastMarketOrderExpired = 0x40c,
+    KeeperTipTooHigh = 0x40e,
+    OrderNotTriggered = 0x410,
+    InvalidPriceOracle = 0x412,
+    StalePriceOracle = 0x414,
+    MinAmountOutNotMet = 0x416,
+    InvalidExpirySlot = 0x418,
+    OrderExpired = 0x41a,
+    OrderNotExpired = 0x41c,
+    RelayerFeeTooHigh = 0x41e,
+    RelayerTokenRequired = 0x420,
+    IntegratorFeeTooHigh = 0x422,
+    InvalidIntegratorToken = 0x424,
+    PreparedFillNotExpired = 0x426,
+    InvalidFallbackToken = 0x428,
+    PreparedFillAmountTooLarge = 0x42a,
+
+    AccountLayoutMismatch = 0x600,
+    UnsupportedAccountVersion = 0x602,
+}
+
+impl common::admin::AdminError for TokenRouterError {
+    fn owner_only() -> anchor_lang::error::Error {
+        anchor_lang::error!(Self::OwnerOnly)
+    }
+
+    fn owner_or_assistant_only() -> anchor_lang::error::Error {
+        anchor_lang::error!(Self::OwnerOrAssistantOnly)
+    }
+
+    fn missing_role() -> anchor_lang::error::Error {
+        anchor_lang::error!(Self::MissingRole)
+    }
 }
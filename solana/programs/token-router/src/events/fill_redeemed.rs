@@ -0,0 +1,16 @@
+use crate::state::FillType;
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct FillRedeemed {
+    pub prepared_fill: Pubkey,
+
+    /// Fill VAA or Matching Engine fast fill account.
+    pub fill_source: Pubkey,
+    pub fill_type: FillType,
+    pub source_chain: u16,
+    pub order_sender: [u8; 32],
+    pub redeemer: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct IntegratorFeePaid {
+    pub prepared_order: Pubkey,
+    pub integrator_token: Pubkey,
+    pub fee_bps: u16,
+    pub amount: u64,
+}
@@ -0,0 +1,17 @@
+mod fill_redeemed;
+pub use fill_redeemed::*;
+
+mod integrator_fee_paid;
+pub use integrator_fee_paid::*;
+
+mod order_placed;
+pub use order_placed::*;
+
+mod order_prepared;
+pub use order_prepared::*;
+
+mod prepared_fill_consumed;
+pub use prepared_fill_consumed::*;
+
+mod prepared_order_closed;
+pub use prepared_order_closed::*;
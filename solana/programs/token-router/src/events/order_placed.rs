@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct OrderPlaced {
+    pub prepared_order: Pubkey,
+    pub order_sender: Pubkey,
+
+    /// Amount burned via CCTP or, for fast market orders, deposited for the Matching Engine after
+    /// fees are paid.
+    pub amount: u64,
+    pub target_chain: u16,
+    pub redeemer: [u8; 32],
+
+    /// Whether this order was published as a fast market order.
+    pub fast: bool,
+}
@@ -0,0 +1,14 @@
+use crate::state::OrderType;
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct OrderPrepared {
+    pub prepared_order: Pubkey,
+    pub order_sender: Pubkey,
+    pub prepared_by: Pubkey,
+    pub order_type: OrderType,
+    pub amount_in: u64,
+    pub target_chain: u16,
+    pub redeemer: [u8; 32],
+}
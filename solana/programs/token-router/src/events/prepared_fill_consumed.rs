@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct PreparedFillConsumed {
+    pub prepared_fill: Pubkey,
+    pub dst_token: Pubkey,
+    pub amount: u64,
+
+    /// Tokens left in the prepared fill's custody token account after this consumption.
+    pub remaining: u64,
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct PreparedOrderClosed {
+    pub prepared_order: Pubkey,
+    pub order_sender: Pubkey,
+    pub refund_token: Pubkey,
+
+    /// Amount refunded to the refund token account.
+    pub amount: u64,
+
+    /// Whether this order was refunded by a keeper after it expired.
+    pub expired: bool,
+}
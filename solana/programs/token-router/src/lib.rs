@@ -5,9 +5,11 @@ mod composite;
 
 mod error;
 
+mod events;
+
 mod processor;
-pub use processor::PrepareMarketOrderArgs;
 use processor::*;
+pub use processor::{ExecuteFillArgs, OrderTrigger, PrepareMarketOrderArgs, EXECUTE_FILL_SELECTOR};
 
 pub mod state;
 
@@ -33,7 +35,12 @@ cfg_if::cfg_if! {
 }
 
 const PREPARED_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"prepared-custody";
+const FINALIZED_CORE_MESSAGE_SEED_PREFIX: &[u8] = b"finalized-core-msg";
 const MAX_REDEEMER_MESSAGE_SIZE: usize = 500;
+const MAX_BPS: u16 = 10_000;
+const MAX_PRICE_ORACLE_AGE_SLOTS: u64 = 150; // ~1 minute
+const REFUND_EXPIRED_ORDER_KEEPER_TIP: u64 = 10_000; // 0.01 USDC
+const PREPARED_FILL_FORWARD_TIMEOUT: i64 = 2_592_000; // 30 days
 
 #[program]
 pub mod token_router {
@@ -63,7 +70,8 @@ pub mod token_router {
 
     /// This instruction confirms the ownership transfer request and sets the new `owner` in the
     /// `Custodian` account. This instruction can only be called by the `pending_owner`. The
-    /// `pending_owner` must be the same as the `pending_owner` in the `Custodian` account.
+    /// `pending_owner` must be the same as the `pending_owner` in the `Custodian` account, and the
+    /// request must be confirmed within `PENDING_OWNER_EXPIRY_SLOTS` of being submitted.
     /// # Arguments
     ///
     /// * `ctx` - `ConfirmOwnershipTransferRequest` context.
@@ -84,8 +92,36 @@ pub mod token_router {
         processor::cancel_ownership_transfer_request(ctx)
     }
 
-    /// This instruction is used to update the `owner_assistant` field in the `Custodian` account. This
-    /// instruction can only be called by the `owner`.
+    /// This instruction sets the `pending_owner_set` field in the `Custodian` account. This
+    /// instruction can only be called by the `owner` (or enough members of the active owner set).
+    /// An owner set with a zero threshold deactivates multisig ownership.
+    /// # Arguments
+    ///
+    /// * `ctx`       - `SubmitOwnerSetTransferRequest` context.
+    /// * `owner_set` - The new M-of-N `OwnerSet`.
+    pub fn submit_owner_set_transfer_request(
+        ctx: Context<SubmitOwnerSetTransferRequest>,
+        owner_set: common::admin::OwnerSet,
+    ) -> Result<()> {
+        processor::submit_owner_set_transfer_request(ctx, owner_set)
+    }
+
+    /// This instruction confirms the owner set transfer request and replaces the `owner_set` in
+    /// the `Custodian` account. The transaction must be signed by enough members of both the
+    /// current and the pending owner set. These signers are passed in as remaining accounts.
+    /// Cancelling an ownership transfer request also cancels a pending owner set.
+    /// # Arguments
+    ///
+    /// * `ctx` - `ConfirmOwnerSetTransferRequest` context.
+    pub fn confirm_owner_set_transfer_request(
+        ctx: Context<ConfirmOwnerSetTransferRequest>,
+    ) -> Result<()> {
+        processor::confirm_owner_set_transfer_request(ctx)
+    }
+
+    /// This instruction is used to update the `owner_assistant` field in the `Custodian` account. The
+    /// previous assistant remains authorized for `OWNER_ASSISTANT_ROTATION_SLOTS` unless the
+    /// rotation is completed earlier. This instruction can only be called by the `owner`.
     /// # Arguments
     ///
     /// * `ctx` - `UpdateOwnerAssistant` context.
@@ -93,8 +129,83 @@ pub mod token_router {
         processor::update_owner_assistant(ctx)
     }
 
-    /// This instruction is used to pause or unpause further processing of new transfer. Only the `owner`
-    /// or `owner_assistant` can pause the program.
+    /// This instruction completes an owner assistant rotation by revoking the previous
+    /// `owner_assistant` before its rotation window expires. This instruction can only be called
+    /// by the `owner` or `owner_assistant`.
+    /// # Arguments
+    ///
+    /// * `ctx` - `CompleteOwnerAssistantRotation` context.
+    pub fn complete_owner_assistant_rotation(
+        ctx: Context<CompleteOwnerAssistantRotation>,
+    ) -> Result<()> {
+        processor::complete_owner_assistant_rotation(ctx)
+    }
+
+    /// This instruction is used to update the `max_integrator_fee_bps` field in the `Custodian`
+    /// account, which caps the integrator fee an order can pay. This instruction can only be
+    /// called by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx`         - `UpdateMaxIntegratorFee` context.
+    /// * `max_fee_bps` - Maximum integrator fee in bps.
+    pub fn update_max_integrator_fee(
+        ctx: Context<UpdateMaxIntegratorFee>,
+        max_fee_bps: u16,
+    ) -> Result<()> {
+        processor::update_max_integrator_fee(ctx, max_fee_bps)
+    }
+
+    /// This instruction is used to allow a redeemer program to receive fills via
+    /// `redeem_and_execute`. Fill tokens are transferred to the `redeemer_token` account before
+    /// the redeemer program is invoked. This instruction can only be called by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx` - `AddAllowedRedeemer` context.
+    pub fn add_allowed_redeemer(ctx: Context<AddAllowedRedeemer>) -> Result<()> {
+        processor::add_allowed_redeemer(ctx)
+    }
+
+    /// This instruction is used to disallow a redeemer program from receiving fills via
+    /// `redeem_and_execute`. This instruction can only be called by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx` - `RemoveAllowedRedeemer` context.
+    pub fn remove_allowed_redeemer(ctx: Context<RemoveAllowedRedeemer>) -> Result<()> {
+        processor::remove_allowed_redeemer(ctx)
+    }
+
+    /// This instruction is used to register a `PriceOracle` account whose prices are published by
+    /// the `authority`. Limit orders can only be triggered by registered price oracles. This
+    /// instruction can only be called by the `owner` or `owner_assistant`.
+    /// # Arguments
+    ///
+    /// * `ctx` - `AddPriceOracle` context.
+    pub fn add_price_oracle(ctx: Context<AddPriceOracle>) -> Result<()> {
+        processor::add_price_oracle(ctx)
+    }
+
+    /// This instruction is used to deregister a `PriceOracle` account. Limit orders naming this
+    /// oracle can no longer be triggered and can only be closed by their sender or refunded once expired. This
+    /// instruction can only be called by the `owner` or `owner_assistant`.
+    /// # Arguments
+    ///
+    /// * `ctx` - `RemovePriceOracle` context.
+    pub fn remove_price_oracle(ctx: Context<RemovePriceOracle>) -> Result<()> {
+        processor::remove_price_oracle(ctx)
+    }
+
+    /// This instruction is used to update the `fill_rescue_token` field in the `Custodian` account,
+    /// where expired prepared fills from foreign chains are forwarded. This instruction can only be
+    /// called by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx` - `UpdateFillRescueToken` context.
+    pub fn update_fill_rescue_token(ctx: Context<UpdateFillRescueToken>) -> Result<()> {
+        processor::update_fill_rescue_token(ctx)
+    }
+
+    /// This instruction is used to pause or unpause further processing of new transfer. Only the `owner`,
+    /// `owner_assistant` or a `Pauser` can pause the program.
     /// # Arguments
     ///
     /// * `ctx`   - `SetPause` context.
@@ -103,6 +214,37 @@ pub mod token_router {
         processor::set_pause(ctx, paused)
     }
 
+    /// This instruction is used to grant a role to a key other than the `owner` and
+    /// `owner_assistant`. The role registry is created if it does not exist yet. This instruction
+    /// can only be called by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx`    - `UpdateRole` context.
+    /// * `member` - Key to grant the role to.
+    /// * `role`   - Role to grant.
+    pub fn grant_role(
+        ctx: Context<UpdateRole>,
+        member: Pubkey,
+        role: common::admin::Role,
+    ) -> Result<()> {
+        processor::grant_role(ctx, member, role)
+    }
+
+    /// This instruction is used to revoke a role. A key without any remaining roles is removed from
+    /// the role registry. This instruction can only be called by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx`    - `UpdateRole` context.
+    /// * `member` - Key to revoke the role from.
+    /// * `role`   - Role to revoke.
+    pub fn revoke_role(
+        ctx: Context<UpdateRole>,
+        member: Pubkey,
+        role: common::admin::Role,
+    ) -> Result<()> {
+        processor::revoke_role(ctx, member, role)
+    }
+
     /// This instruction is used for executing logic during an upgrade. This instruction can only be
     /// called by the `upgrade_manager_program`.
     /// # Arguments
@@ -112,6 +254,30 @@ pub mod token_router {
         processor::migrate(ctx)
     }
 
+    /// This instruction is used to verify that the `Custodian` account (and any other versioned
+    /// accounts passed in as remaining accounts) have the expected size, can be deserialized and
+    /// were migrated to the current schema version. The `upgrade_manager_program` invokes this
+    /// instruction before committing an upgrade, forwarding the accounts passed to the commit.
+    /// # Arguments
+    ///
+    /// * `ctx` - `HealthCheck` context.
+    pub fn health_check(ctx: Context<HealthCheck>) -> Result<()> {
+        processor::health_check(ctx)
+    }
+
+    /// This instruction is used to upgrade versioned accounts (`Custodian`, `RoleRegistry` and
+    /// `AllowedRedeemer`) to their current schema version. The accounts are passed in as remaining
+    /// accounts and are reallocated if necessary, with the payer covering the additional rent.
+    /// Anyone can call this instruction.
+    /// # Arguments
+    ///
+    /// * `ctx` - `MigrateAccounts` context.
+    pub fn migrate_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateAccounts<'info>>,
+    ) -> Result<()> {
+        processor::migrate_accounts(ctx)
+    }
+
     /// This instruction is used to prepare a `PrepareOrder` account for a market order. The `amount_in`
     /// is transferred from the `source` account to the `prepared_custody_token` account. Anyone
     /// can call this instruction.
@@ -130,6 +296,8 @@ pub mod token_router {
     /// an outbound transfer in case the order is no longer needed, or they made a mistake
     /// in the order. The `prepared_custody_token` account is closed and the tokens are refunded
     /// to the `refund_token` account. This instruction can only be called by the `order_sender`.
+    /// Limit and time-locked orders that never trigger are closed this way, and their keeper tips
+    /// are refunded with the rest of the tokens.
     /// # Arguments
     ///
     /// * `ctx` - `ClosePreparedOrder` context.
@@ -137,11 +305,23 @@ pub mod token_router {
         processor::close_prepared_order(ctx)
     }
 
+    /// This instruction is used to refund a `PreparedOrder` account once its expiry slot has
+    /// passed. The tokens in the `prepared_custody_token` account are refunded to the
+    /// `refund_token` account, less a keeper tip of up to `REFUND_EXPIRED_ORDER_KEEPER_TIP` paid to
+    /// the `keeper_token` account. Rent is returned to `prepared_by`. Anyone can call this
+    /// instruction.
+    /// # Arguments
+    ///
+    /// * `ctx` - `RefundExpiredOrder` context.
+    pub fn refund_expired_order(ctx: Context<RefundExpiredOrder>) -> Result<()> {
+        processor::refund_expired_order(ctx)
+    }
+
     /// This instruction is used to place a `MarketOrder`. This order type transfers tokens
     /// from Solana to another registered Token Router endpoint on a different chain. This
     /// instruction requires a `prepared_market_order` account to be present. Note: this
-    /// is the only order type on the Solana Token Router currently, and does not pass
-    /// through the matching engine.
+    /// order type does not pass through the matching engine (see `place_fast_market_order`). If
+    /// the order has a relayer fee, it is paid to the `relayer_token` account.
     /// # Arguments
     ///
     /// * `ctx` - `PlaceMarketOrder` context.
@@ -149,6 +329,52 @@ pub mod token_router {
         processor::place_market_order_cctp(ctx)
     }
 
+    /// This instruction is used to place a `Limit` order once the price reported by its oracle
+    /// meets the order's condition. The price must have been published within
+    /// `MAX_PRICE_ORACLE_AGE_SLOTS`. Anyone can call this instruction, and the keeper tip encoded
+    /// in the order is paid to the `keeper_token` account. The rest of the tokens are transferred
+    /// like a `MarketOrder`.
+    /// # Arguments
+    ///
+    /// * `ctx` - `PlaceLimitOrderCctp` context.
+    pub fn place_limit_order_cctp(ctx: Context<PlaceLimitOrderCctp>) -> Result<()> {
+        processor::place_limit_order_cctp(ctx)
+    }
+
+    /// This instruction is used to place a `TimeLocked` order once its unlock slot is reached.
+    /// Anyone can call this instruction, and the keeper tip encoded in the order is paid to the
+    /// `keeper_token` account. The rest of the tokens are transferred like a `MarketOrder`.
+    /// # Arguments
+    ///
+    /// * `ctx` - `PlaceTimeLockedOrderCctp` context.
+    pub fn place_time_locked_order_cctp(ctx: Context<PlaceTimeLockedOrderCctp>) -> Result<()> {
+        processor::place_time_locked_order_cctp(ctx)
+    }
+
+    /// This instruction is used to publish a price to the `authority`'s `PriceOracle` account,
+    /// which must have been registered via `add_price_oracle`. Limit orders name the oracle whose
+    /// price triggers them.
+    /// # Arguments
+    ///
+    /// * `ctx`   - `UpdatePriceOracle` context.
+    /// * `price` - Price to publish.
+    pub fn update_price_oracle(ctx: Context<UpdatePriceOracle>, price: u64) -> Result<()> {
+        processor::update_price_oracle(ctx, price)
+    }
+
+    /// This instruction is used to place a fast market order, which is auctioned by the Matching
+    /// Engine. The order must have been prepared with fast order parameters. CCTP cannot mint
+    /// within Solana's own domain, so the funds are deposited into a custody token account owned by
+    /// the Matching Engine instead of being burned. A finalized copy of the `FastMarketOrder`
+    /// takes the place of the slow order response, and the `FastMarketOrder` itself is published
+    /// with confirmed commitment so its auction can start before finality.
+    /// # Arguments
+    ///
+    /// * `ctx` - `PlaceFastMarketOrder` context.
+    pub fn place_fast_market_order(ctx: Context<PlaceFastMarketOrder>) -> Result<()> {
+        processor::place_fast_market_order(ctx)
+    }
+
     /// This instruction is used to redeem a `Fill` VAA and redeem tokens from a CCTP transfer. After
     /// the tokens are minted by the CCTP program, they are transferred to a token custody account.
     /// The `prepared_fill` account is populated with information from the `Fill` vaa. This
@@ -172,6 +398,23 @@ pub mod token_router {
         processor::redeem_fast_fill(ctx)
     }
 
+    /// This instruction is used to redeem a `Fill` VAA and redeem tokens from a CCTP transfer without
+    /// creating a `prepared_fill` account. The `redeemer` encoded in the fill must be an allowed
+    /// redeemer program. The minted tokens are transferred to the allowed redeemer's token account
+    /// and the redeemer program's `execute_fill` instruction is invoked with the fill's data and
+    /// the remaining accounts, signed by the redeemer's own authority PDA (not the custodian). This
+    /// instruction only handles CCTP transfers.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `RedeemAndExecute` context.
+    /// * `args` - `CctpMessageArgs` struct, see `redeem_fill/cctp.rs` for more info.
+    pub fn redeem_and_execute<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemAndExecute<'info>>,
+        args: CctpMessageArgs,
+    ) -> Result<()> {
+        processor::redeem_and_execute(ctx, args)
+    }
+
     /// This instruction is used to consume a `prepared_fill` account. The tokens are transferred from the
     /// `prepared_custody_token` account to the `dst_token` account. The `prepared_custody_token` account is
     /// closed. This instruction can only be called by the `redeemer` that is saved in the `prepared_fill`.
@@ -181,6 +424,38 @@ pub mod token_router {
     pub fn consume_prepared_fill(ctx: Context<ConsumePreparedFill>) -> Result<()> {
         processor::consume_prepared_fill(ctx)
     }
+
+    /// This instruction is used to consume part of a `prepared_fill` account. The `amount` is
+    /// transferred from the `prepared_custody_token` account to the `dst_token` account, so a fill
+    /// can be split between several recipients. The `prepared_fill` and `prepared_custody_token`
+    /// accounts are closed once they are drained. This instruction can only be called by the
+    /// `redeemer` that is saved in the `prepared_fill`.
+    /// # Arguments
+    ///
+    /// * `ctx`    - `ConsumePreparedFillPartial` context.
+    /// * `amount` - Amount of tokens to transfer.
+    pub fn consume_prepared_fill_partial(
+        ctx: Context<ConsumePreparedFillPartial>,
+        amount: u64,
+    ) -> Result<()> {
+        processor::consume_prepared_fill_partial(ctx, amount)
+    }
+
+    /// This instruction is used to forward the tokens of a `prepared_fill` account that was never
+    /// consumed by its `redeemer` after `PREPARED_FILL_FORWARD_TIMEOUT`. If the order was sent
+    /// from Solana, the tokens are forwarded to a token account owned by the `order_sender`.
+    /// Otherwise they are forwarded to the `fill_rescue_token` set by the `owner`. Rent is returned
+    /// to `prepared_by`. Anyone can call this instruction.
+    ///
+    /// NOTE: The order sender on a foreign chain has no Solana account to forward to, so these
+    /// tokens are entrusted to the owner, who controls the `fill_rescue_token` account and is
+    /// expected to return them to the order sender or redeemer off-chain.
+    /// # Arguments
+    ///
+    /// * `ctx` - `ForwardExpiredPreparedFill` context.
+    pub fn forward_expired_prepared_fill(ctx: Context<ForwardExpiredPreparedFill>) -> Result<()> {
+        processor::forward_expired_prepared_fill(ctx)
+    }
 }
 
 #[cfg(test)]
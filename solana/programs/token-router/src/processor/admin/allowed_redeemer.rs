@@ -0,0 +1,69 @@
+use crate::{composite::*, state::AllowedRedeemer};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::admin::utils::migrate::VersionedAccount;
+
+#[derive(Accounts)]
+pub struct AddAllowedRedeemer<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnly<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AllowedRedeemer::INIT_SPACE,
+        seeds = [
+            AllowedRedeemer::SEED_PREFIX,
+            redeemer_program.key().as_ref(),
+        ],
+        bump,
+    )]
+    allowed_redeemer: Account<'info, AllowedRedeemer>,
+
+    /// CHECK: Must be an executable program.
+    #[account(executable)]
+    redeemer_program: UncheckedAccount<'info>,
+
+    #[account(token::mint = common::USDC_MINT)]
+    redeemer_token: Account<'info, token::TokenAccount>,
+
+    system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveAllowedRedeemer<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnly<'info>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            AllowedRedeemer::SEED_PREFIX,
+            allowed_redeemer.program_id.as_ref(),
+        ],
+        bump = allowed_redeemer.bump,
+    )]
+    allowed_redeemer: Account<'info, AllowedRedeemer>,
+}
+
+pub fn add_allowed_redeemer(ctx: Context<AddAllowedRedeemer>) -> Result<()> {
+    ctx.accounts.allowed_redeemer.set_inner(AllowedRedeemer {
+        bump: ctx.bumps.allowed_redeemer,
+        program_id: ctx.accounts.redeemer_program.key(),
+        redeemer_token: ctx.accounts.redeemer_token.key(),
+        version: AllowedRedeemer::VERSION,
+    });
+
+    // Done.
+    Ok(())
+}
+
+pub fn remove_allowed_redeemer(_ctx: Context<RemoveAllowedRedeemer>) -> Result<()> {
+    // Done.
+    Ok(())
+}
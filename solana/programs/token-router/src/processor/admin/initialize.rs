@@ -1,6 +1,7 @@
 use crate::{composite::*, error::TokenRouterError, state::Custodian};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
+use common::admin::utils::migrate::VersionedAccount;
 use wormhole_solana_utils::cpi::bpf_loader_upgradeable::{self, BpfLoaderUpgradeable};
 
 #[derive(Accounts)]
@@ -109,6 +110,13 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         owner,
         pending_owner: None,
         owner_assistant: ctx.accounts.owner_assistant.key(),
+        owner_set: Default::default(),
+        pending_owner_set: None,
+        pending_owner_window: Default::default(),
+        owner_assistant_rotation: None,
+        version: Custodian::VERSION,
+        max_integrator_fee_bps: 0,
+        fill_rescue_token: None,
     });
 
     // Done.
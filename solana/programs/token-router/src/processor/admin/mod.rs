@@ -1,15 +1,30 @@
 // mod authorize_upgrade;
 // pub use authorize_upgrade::*;
 
+mod allowed_redeemer;
+pub use allowed_redeemer::*;
+
+mod health_check;
+pub use health_check::*;
+
 mod initialize;
 pub use initialize::*;
 
 mod migrate;
 pub use migrate::*;
 
+mod migrate_accounts;
+pub use migrate_accounts::*;
+
 mod ownership_transfer_request;
 pub use ownership_transfer_request::*;
 
+mod price_oracle;
+pub use price_oracle::*;
+
+mod role;
+pub use role::*;
+
 mod set_pause;
 pub use set_pause::*;
 
@@ -9,9 +9,9 @@ pub struct CancelOwnershipTransferRequest<'info> {
 pub fn cancel_ownership_transfer_request(
     ctx: Context<CancelOwnershipTransferRequest>,
 ) -> Result<()> {
-    common::admin::utils::pending_owner::cancel_transfer_ownership(
-        &mut ctx.accounts.admin.custodian,
-    );
+    let custodian = &mut ctx.accounts.admin.custodian;
+    common::admin::utils::pending_owner::cancel_transfer_ownership(custodian);
+    common::admin::utils::multisig::cancel_transfer_owner_set(custodian);
 
     // Done.
     Ok(())
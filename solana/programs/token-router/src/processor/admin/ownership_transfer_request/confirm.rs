@@ -17,6 +17,9 @@ pub struct ConfirmOwnershipTransferRequest<'info> {
         constraint = {
             pending_owner::only_pending_owner_unchecked(&custodian, &pending_owner.key())
         } @ TokenRouterError::NotPendingOwner,
+        constraint = {
+            !pending_owner::is_pending_owner_expired(&custodian, Clock::get()?.slot)
+        } @ TokenRouterError::PendingOwnerExpired,
     )]
     custodian: Account<'info, Custodian>,
 }
@@ -4,5 +4,11 @@ pub use cancel::*;
 mod confirm;
 pub use confirm::*;
 
+mod confirm_owner_set;
+pub use confirm_owner_set::*;
+
 mod submit;
 pub use submit::*;
+
+mod submit_owner_set;
+pub use submit_owner_set::*;
@@ -21,6 +21,7 @@ pub fn submit_ownership_transfer_request(
     common::admin::utils::pending_owner::transfer_ownership(
         &mut ctx.accounts.admin.custodian,
         &ctx.accounts.new_owner.key(),
+        Clock::get()?.slot,
     );
 
     // Done.
@@ -0,0 +1,65 @@
+use crate::{composite::*, state::PriceOracle};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AddPriceOracle<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: Admin<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PriceOracle::INIT_SPACE,
+        seeds = [
+            PriceOracle::SEED_PREFIX,
+            authority.key().as_ref(),
+        ],
+        bump,
+    )]
+    price_oracle: Account<'info, PriceOracle>,
+
+    /// CHECK: Key allowed to publish prices to the new price oracle.
+    authority: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemovePriceOracle<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: Admin<'info>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            PriceOracle::SEED_PREFIX,
+            price_oracle.authority.as_ref(),
+        ],
+        bump = price_oracle.bump,
+    )]
+    price_oracle: Account<'info, PriceOracle>,
+}
+
+pub fn add_price_oracle(ctx: Context<AddPriceOracle>) -> Result<()> {
+    // No price has been published yet, so limit orders cannot be triggered by this oracle until its
+    // authority publishes one.
+    ctx.accounts.price_oracle.set_inner(PriceOracle {
+        bump: ctx.bumps.price_oracle,
+        authority: ctx.accounts.authority.key(),
+        price: 0,
+        publish_slot: 0,
+    });
+
+    // Done.
+    Ok(())
+}
+
+pub fn remove_price_oracle(_ctx: Context<RemovePriceOracle>) -> Result<()> {
+    // Done.
+    Ok(())
+}
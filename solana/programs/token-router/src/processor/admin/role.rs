@@ -0,0 +1,47 @@
+use crate::{composite::*, error::TokenRouterError, state::RoleRegistry};
+use anchor_lang::prelude::*;
+use common::admin::{utils::migrate::VersionedAccount, HasRoles, Role};
+
+#[derive(Accounts)]
+pub struct UpdateRole<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnly<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RoleRegistry::INIT_SPACE,
+        seeds = [RoleRegistry::SEED_PREFIX],
+        bump,
+    )]
+    role_registry: Box<Account<'info, RoleRegistry>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn grant_role(ctx: Context<UpdateRole>, member: Pubkey, role: Role) -> Result<()> {
+    let role_registry = &mut ctx.accounts.role_registry;
+    role_registry.bump = ctx.bumps.role_registry;
+    role_registry.version = RoleRegistry::VERSION;
+
+    require!(
+        role_registry.grant_role(&member, role),
+        TokenRouterError::RoleRegistryFull
+    );
+
+    // Done.
+    Ok(())
+}
+
+pub fn revoke_role(ctx: Context<UpdateRole>, member: Pubkey, role: Role) -> Result<()> {
+    let role_registry = &mut ctx.accounts.role_registry;
+    role_registry.bump = ctx.bumps.role_registry;
+    role_registry.version = RoleRegistry::VERSION;
+
+    role_registry.revoke_role(&member, role);
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use crate::composite::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+pub struct UpdateFillRescueToken<'info> {
+    admin: OwnerOnlyMut<'info>,
+
+    /// New rescue token account.
+    #[account(token::mint = common::USDC_MINT)]
+    new_fill_rescue_token: Account<'info, token::TokenAccount>,
+}
+
+pub fn update_fill_rescue_token(ctx: Context<UpdateFillRescueToken>) -> Result<()> {
+    ctx.accounts.admin.custodian.fill_rescue_token = Some(ctx.accounts.new_fill_rescue_token.key());
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use crate::{composite::*, error::TokenRouterError};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateMaxIntegratorFee<'info> {
+    admin: OwnerOnlyMut<'info>,
+}
+
+pub fn update_max_integrator_fee(
+    ctx: Context<UpdateMaxIntegratorFee>,
+    max_fee_bps: u16,
+) -> Result<()> {
+    require!(
+        max_fee_bps <= crate::MAX_BPS,
+        TokenRouterError::IntegratorFeeBpsTooLarge
+    );
+
+    ctx.accounts.admin.custodian.max_integrator_fee_bps = max_fee_bps;
+
+    // Done.
+    Ok(())
+}
@@ -1,2 +1,11 @@
+mod fill_rescue_token;
+pub use fill_rescue_token::*;
+
+mod max_integrator_fee;
+pub use max_integrator_fee::*;
+
 mod owner_assistant;
 pub use owner_assistant::*;
+
+mod owner_assistant_rotation;
+pub use owner_assistant_rotation::*;
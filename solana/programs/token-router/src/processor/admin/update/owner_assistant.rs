@@ -17,9 +17,11 @@ pub struct UpdateOwnerAssistant<'info> {
 }
 
 pub fn update_owner_assistant(ctx: Context<UpdateOwnerAssistant>) -> Result<()> {
-    common::admin::utils::assistant::transfer_owner_assistant(
+    // The previous assistant remains authorized until the rotation window expires.
+    common::admin::utils::assistant::rotate_owner_assistant(
         &mut ctx.accounts.admin.custodian,
         &ctx.accounts.new_owner_assistant,
+        Clock::get()?.slot,
     );
 
     // Done.
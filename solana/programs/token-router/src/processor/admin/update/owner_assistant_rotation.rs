@@ -0,0 +1,21 @@
+use crate::{composite::*, error::TokenRouterError};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CompleteOwnerAssistantRotation<'info> {
+    admin: AdminMut<'info>,
+}
+
+pub fn complete_owner_assistant_rotation(
+    ctx: Context<CompleteOwnerAssistantRotation>,
+) -> Result<()> {
+    require!(
+        common::admin::utils::assistant::complete_owner_assistant_rotation(
+            &mut ctx.accounts.admin.custodian
+        ),
+        TokenRouterError::NoOwnerAssistantRotation
+    );
+
+    // Done.
+    Ok(())
+}
@@ -1,11 +1,13 @@
 use crate::{
     composite::*,
+    events::PreparedOrderClosed,
     state::{Custodian, PreparedOrder},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 
 /// Accounts required for [close_prepared_order].
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClosePreparedOrder<'info> {
     custodian: CheckedCustodian<'info>,
@@ -65,6 +67,14 @@ pub fn close_prepared_order(ctx: Context<ClosePreparedOrder>) -> Result<()> {
         ctx.accounts.prepared_custody_token.amount,
     )?;
 
+    emit_cpi!(PreparedOrderClosed {
+        prepared_order: ctx.accounts.prepared_order.key(),
+        order_sender: ctx.accounts.prepared_order.order_sender,
+        refund_token: ctx.accounts.refund_token.key(),
+        amount: ctx.accounts.prepared_custody_token.amount,
+        expired: false,
+    });
+
     // Finally close token account.
     token::close_account(CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
@@ -1,8 +1,9 @@
-use crate::state::PreparedFill;
+use crate::{error::TokenRouterError, events::PreparedFillConsumed, state::PreparedFill};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 
 /// Accounts required for [consume_prepared_fill].
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ConsumePreparedFill<'info> {
     /// This signer must be the same one encoded in the prepared fill.
@@ -72,6 +73,13 @@ pub fn consume_prepared_fill(ctx: Context<ConsumePreparedFill>) -> Result<()> {
         custody_token.amount,
     )?;
 
+    emit_cpi!(PreparedFillConsumed {
+        prepared_fill: prepared_fill.key(),
+        dst_token: ctx.accounts.dst_token.key(),
+        amount: custody_token.amount,
+        remaining: 0,
+    });
+
     // Finally close token account.
     token::close_account(CpiContext::new_with_signer(
         token_program.to_account_info(),
@@ -83,3 +91,105 @@ pub fn consume_prepared_fill(ctx: Context<ConsumePreparedFill>) -> Result<()> {
         &[prepared_fill_signer_seeds],
     ))
 }
+
+/// Accounts required for [consume_prepared_fill_partial].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConsumePreparedFillPartial<'info> {
+    /// This signer must be the same one encoded in the prepared fill.
+    #[account(address = prepared_fill.redeemer)]
+    redeemer: Signer<'info>,
+
+    /// CHECK: This recipient may not necessarily be the same one encoded in the prepared fill (as
+    /// the payer). It receives the closed account funds once the prepared fill is drained.
+    #[account(mut)]
+    beneficiary: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    prepared_fill: Account<'info, PreparedFill>,
+
+    /// Destination token account, which the redeemer may not own.
+    ///
+    /// CHECK: This token account must already exist.
+    #[account(mut)]
+    dst_token: UncheckedAccount<'info>,
+
+    /// Custody token account. This account will be closed once it is drained.
+    ///
+    /// CHECK: Mutable. Seeds must be \["custody"\].
+    #[account(
+        mut,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_fill.key().as_ref(),
+        ],
+        bump = prepared_fill.prepared_custody_token_bump,
+    )]
+    prepared_custody_token: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+/// Transfer part of a prepared fill's tokens to the destination token account. The prepared fill
+/// and its custody token account are closed once all of the tokens are consumed.
+pub fn consume_prepared_fill_partial(
+    ctx: Context<ConsumePreparedFillPartial>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, TokenRouterError::InsufficientAmount);
+
+    let prepared_fill = &ctx.accounts.prepared_fill;
+
+    let prepared_fill_signer_seeds = &[
+        PreparedFill::SEED_PREFIX,
+        prepared_fill.seeds.fill_source.as_ref(),
+        &[prepared_fill.seeds.bump],
+    ];
+
+    let custody_token = &ctx.accounts.prepared_custody_token;
+    let token_program = &ctx.accounts.token_program;
+
+    let remaining = custody_token
+        .amount
+        .checked_sub(amount)
+        .ok_or_else(|| TokenRouterError::PreparedFillAmountTooLarge)?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: custody_token.to_account_info(),
+                to: ctx.accounts.dst_token.to_account_info(),
+                authority: prepared_fill.to_account_info(),
+            },
+            &[prepared_fill_signer_seeds],
+        ),
+        amount,
+    )?;
+
+    emit_cpi!(PreparedFillConsumed {
+        prepared_fill: prepared_fill.key(),
+        dst_token: ctx.accounts.dst_token.key(),
+        amount,
+        remaining,
+    });
+
+    if remaining == 0 {
+        token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::CloseAccount {
+                account: custody_token.to_account_info(),
+                destination: ctx.accounts.beneficiary.to_account_info(),
+                authority: prepared_fill.to_account_info(),
+            },
+            &[prepared_fill_signer_seeds],
+        ))?;
+
+        ctx.accounts
+            .prepared_fill
+            .close(ctx.accounts.beneficiary.to_account_info())?;
+    }
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use crate::{
+    composite::*, error::TokenRouterError, events::PreparedFillConsumed, state::PreparedFill,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN;
+
+/// Accounts required for [forward_expired_prepared_fill].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ForwardExpiredPreparedFill<'info> {
+    custodian: CheckedCustodian<'info>,
+
+    /// CHECK: This payer must be the same one encoded in the prepared fill.
+    #[account(
+        mut,
+        address = prepared_fill.prepared_by,
+    )]
+    prepared_by: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = prepared_by,
+        constraint = {
+            require!(
+                Clock::get()?.unix_timestamp
+                    >= prepared_fill
+                        .timestamp
+                        .saturating_add(crate::PREPARED_FILL_FORWARD_TIMEOUT),
+                TokenRouterError::PreparedFillNotExpired
+            );
+
+            true
+        }
+    )]
+    prepared_fill: Account<'info, PreparedFill>,
+
+    /// Token account where the tokens are forwarded. If the order was sent from Solana, this
+    /// account must be owned by the order sender. Otherwise it must be the custodian's fill rescue
+    /// token account.
+    #[account(
+        mut,
+        token::mint = common::USDC_MINT,
+        constraint = {
+            if prepared_fill.source_chain == SOLANA_CHAIN {
+                require_keys_eq!(
+                    fallback_token.owner,
+                    Pubkey::from(prepared_fill.order_sender),
+                    TokenRouterError::InvalidFallbackToken
+                );
+            } else {
+                require!(
+                    custodian.fill_rescue_token == Some(fallback_token.key()),
+                    TokenRouterError::InvalidFallbackToken
+                );
+            }
+
+            true
+        }
+    )]
+    fallback_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Custody token account. This account will be closed at the end of this instruction.
+    ///
+    /// CHECK: Mutable. Seeds must be \["custody"\, prepared_fill.key()].
+    #[account(
+        mut,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_fill.key().as_ref(),
+        ],
+        bump = prepared_fill.prepared_custody_token_bump,
+    )]
+    prepared_custody_token: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+/// Forward the tokens of a prepared fill its redeemer never consumed, so the fill's tokens are not
+/// stuck in custody forever.
+pub fn forward_expired_prepared_fill(ctx: Context<ForwardExpiredPreparedFill>) -> Result<()> {
+    let prepared_fill = &ctx.accounts.prepared_fill;
+
+    let prepared_fill_signer_seeds = &[
+        PreparedFill::SEED_PREFIX,
+        prepared_fill.seeds.fill_source.as_ref(),
+        &[prepared_fill.seeds.bump],
+    ];
+
+    let custody_token = &ctx.accounts.prepared_custody_token;
+    let token_program = &ctx.accounts.token_program;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: custody_token.to_account_info(),
+                to: ctx.accounts.fallback_token.to_account_info(),
+                authority: prepared_fill.to_account_info(),
+            },
+            &[prepared_fill_signer_seeds],
+        ),
+        custody_token.amount,
+    )?;
+
+    emit_cpi!(PreparedFillConsumed {
+        prepared_fill: prepared_fill.key(),
+        dst_token: ctx.accounts.fallback_token.key(),
+        amount: custody_token.amount,
+        remaining: 0,
+    });
+
+    // Finally close token account.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: custody_token.to_account_info(),
+            destination: ctx.accounts.prepared_by.to_account_info(),
+            authority: prepared_fill.to_account_info(),
+        },
+        &[prepared_fill_signer_seeds],
+    ))
+}
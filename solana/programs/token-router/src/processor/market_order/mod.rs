@@ -1,5 +1,8 @@
 mod place_cctp;
 pub use place_cctp::*;
 
+mod place_fast;
+pub use place_fast::*;
+
 mod prepare;
 pub use prepare::*;
@@ -1,7 +1,8 @@
 use crate::{
     composite::*,
     error::TokenRouterError,
-    state::{Custodian, PreparedOrder},
+    events::{IntegratorFeePaid, OrderPlaced},
+    state::{Custodian, OrderType, PreparedOrder, PriceOracle},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -15,9 +16,11 @@ use common::{
 };
 
 /// Accounts required for [place_market_order_cctp].
+#[event_cpi]
 #[derive(Accounts)]
 pub struct PlaceMarketOrderCctp<'info> {
-    /// This account must be the same pubkey as the one who prepared the order.
+    /// This account must be the same pubkey as the one who prepared the order. For limit and
+    /// time-locked orders, this is the keeper placing the order.
     #[account(mut)]
     payer: Signer<'info>,
 
@@ -34,6 +37,14 @@ pub struct PlaceMarketOrderCctp<'info> {
     #[account(
         mut,
         close = prepared_by,
+        constraint = {
+            require!(
+                !prepared_order.is_expired(Clock::get()?.slot),
+                TokenRouterError::OrderExpired
+            );
+
+            true
+        }
     )]
     prepared_order: Box<Account<'info, PreparedOrder>>,
 
@@ -154,6 +165,104 @@ pub struct PlaceMarketOrderCctp<'info> {
     /// CHECK: Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.
     #[account(address = solana_program::sysvar::rent::id())]
     rent: UncheckedAccount<'info>,
+
+    /// Token account where the relayer fee is paid. Required if the prepared order has a relayer
+    /// fee.
+    #[account(
+        mut,
+        token::mint = common::USDC_MINT,
+    )]
+    relayer_token: Option<Box<Account<'info, token::TokenAccount>>>,
+
+    /// Token account where the integrator fee is paid. Required if the prepared order has an
+    /// integrator fee.
+    #[account(
+        mut,
+        token::mint = common::USDC_MINT,
+        constraint = {
+            require!(
+                prepared_order
+                    .integrator_fee
+                    .is_some_and(|integrator_fee| integrator_fee.token == integrator_token.key()),
+                TokenRouterError::InvalidIntegratorToken
+            );
+
+            true
+        }
+    )]
+    integrator_token: Option<Box<Account<'info, token::TokenAccount>>>,
+}
+
+/// Accounts required for [place_limit_order_cctp].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PlaceLimitOrderCctp<'info> {
+    place_order: PlaceMarketOrderCctp<'info>,
+
+    #[account(
+        seeds = [
+            PriceOracle::SEED_PREFIX,
+            oracle.authority.as_ref(),
+        ],
+        bump = oracle.bump,
+        constraint = {
+            let condition = match place_order.prepared_order.order_type {
+                OrderType::Limit { condition, .. } => condition,
+                _ => return err!(TokenRouterError::InvalidOrderType),
+            };
+            require_keys_eq!(
+                oracle.key(),
+                condition.oracle,
+                TokenRouterError::InvalidPriceOracle
+            );
+            require!(
+                !oracle.is_stale(Clock::get()?.slot),
+                TokenRouterError::StalePriceOracle
+            );
+            require!(
+                condition.is_met(oracle.price),
+                TokenRouterError::OrderNotTriggered
+            );
+
+            true
+        }
+    )]
+    oracle: Account<'info, PriceOracle>,
+
+    /// Token account where the keeper tip is paid.
+    #[account(
+        mut,
+        token::mint = common::USDC_MINT,
+    )]
+    keeper_token: Box<Account<'info, token::TokenAccount>>,
+}
+
+/// Accounts required for [place_time_locked_order_cctp].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PlaceTimeLockedOrderCctp<'info> {
+    #[account(
+        constraint = {
+            let unlock_slot = match place_order.prepared_order.order_type {
+                OrderType::TimeLocked { unlock_slot, .. } => unlock_slot,
+                _ => return err!(TokenRouterError::InvalidOrderType),
+            };
+            require!(
+                Clock::get()?.slot >= unlock_slot,
+                TokenRouterError::OrderNotTriggered
+            );
+
+            true
+        }
+    )]
+    place_order: PlaceMarketOrderCctp<'info>,
+
+    /// Token account where the keeper tip is paid.
+    #[account(
+        mut,
+        token::mint = common::USDC_MINT,
+    )]
+    keeper_token: Box<Account<'info, token::TokenAccount>>,
 }
 
 /// This instruction invokes both Wormhole Core Bridge and CCTP Token Messenger Minter programs to
@@ -161,66 +270,224 @@ pub struct PlaceMarketOrderCctp<'info> {
 ///
 /// See [burn_and_publish](wormhole_cctp_solana::cpi::burn_and_publish) for more details.
 pub fn place_market_order_cctp(ctx: Context<PlaceMarketOrderCctp>) -> Result<()> {
-    match ctx.accounts.target_router_endpoint.protocol {
+    require!(
+        matches!(
+            ctx.accounts.prepared_order.order_type,
+            OrderType::Market { .. }
+        ),
+        TokenRouterError::InvalidOrderType
+    );
+
+    let (order_placed, integrator_fee_paid) =
+        handle_place_order_cctp(ctx.accounts, &ctx.bumps, None)?;
+
+    if let Some(event) = integrator_fee_paid {
+        emit_cpi!(event);
+    }
+    emit_cpi!(order_placed);
+
+    // Done.
+    Ok(())
+}
+
+/// Place a limit order once the price reported by its oracle meets the order's condition. Anyone
+/// can place this order and collect its keeper tip.
+pub fn place_limit_order_cctp(ctx: Context<PlaceLimitOrderCctp>) -> Result<()> {
+    let keeper_tip = match ctx.accounts.place_order.prepared_order.order_type {
+        OrderType::Limit { keeper_tip, .. } => keeper_tip,
+        _ => return err!(TokenRouterError::InvalidOrderType),
+    };
+
+    let (order_placed, integrator_fee_paid) = handle_place_order_cctp(
+        &mut ctx.accounts.place_order,
+        &ctx.bumps.place_order,
+        Some((ctx.accounts.keeper_token.to_account_info(), keeper_tip)),
+    )?;
+
+    if let Some(event) = integrator_fee_paid {
+        emit_cpi!(event);
+    }
+    emit_cpi!(order_placed);
+
+    // Done.
+    Ok(())
+}
+
+/// Place a time-locked order once its unlock slot is reached. Anyone can place this order and
+/// collect its keeper tip.
+pub fn place_time_locked_order_cctp(ctx: Context<PlaceTimeLockedOrderCctp>) -> Result<()> {
+    let keeper_tip = match ctx.accounts.place_order.prepared_order.order_type {
+        OrderType::TimeLocked { keeper_tip, .. } => keeper_tip,
+        _ => return err!(TokenRouterError::InvalidOrderType),
+    };
+
+    let (order_placed, integrator_fee_paid) = handle_place_order_cctp(
+        &mut ctx.accounts.place_order,
+        &ctx.bumps.place_order,
+        Some((ctx.accounts.keeper_token.to_account_info(), keeper_tip)),
+    )?;
+
+    if let Some(event) = integrator_fee_paid {
+        emit_cpi!(event);
+    }
+    emit_cpi!(order_placed);
+
+    // Done.
+    Ok(())
+}
+
+fn handle_place_order_cctp<'info>(
+    accounts: &mut PlaceMarketOrderCctp<'info>,
+    bumps: &PlaceMarketOrderCctpBumps,
+    keeper_tip: Option<(AccountInfo<'info>, u64)>,
+) -> Result<(OrderPlaced, Option<IntegratorFeePaid>)> {
+    match accounts.target_router_endpoint.protocol {
         matching_engine::state::MessageProtocol::Cctp { domain } => {
-            handle_place_market_order_cctp(ctx, domain)
+            handle_place_market_order_cctp(accounts, bumps, domain, keeper_tip)
         }
         _ => err!(TokenRouterError::InvalidCctpEndpoint),
     }
 }
 
-fn handle_place_market_order_cctp(
-    ctx: Context<PlaceMarketOrderCctp>,
+fn handle_place_market_order_cctp<'info>(
+    accounts: &mut PlaceMarketOrderCctp<'info>,
+    bumps: &PlaceMarketOrderCctpBumps,
     destination_cctp_domain: u32,
-) -> Result<()> {
-    let redeemer_message = std::mem::take(&mut ctx.accounts.prepared_order.redeemer_message);
+    keeper_tip: Option<(AccountInfo<'info>, u64)>,
+) -> Result<(OrderPlaced, Option<IntegratorFeePaid>)> {
+    let redeemer_message = std::mem::take(&mut accounts.prepared_order.redeemer_message);
+
+    let custodian = &accounts.custodian;
+    let payer = &accounts.payer;
+    let prepared_order = &accounts.prepared_order;
+    let prepared_custody_token = &accounts.prepared_custody_token;
+    let token_program = &accounts.token_program;
+    let system_program = &accounts.system_program;
+    let target_router_endpoint = &accounts.target_router_endpoint;
+
+    let order_info = &accounts.prepared_order.info;
+
+    let mut amount = prepared_custody_token.amount;
+
+    // Pay the integrator who routed this order. This fee is computed from the full amount in.
+    let integrator_fee_paid = match prepared_order.integrator_fee {
+        Some(integrator_fee) => {
+            let integrator_token = accounts
+                .integrator_token
+                .as_ref()
+                .ok_or_else(|| TokenRouterError::InvalidIntegratorToken)?;
+            let fee = integrator_fee.amount(amount);
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: prepared_custody_token.to_account_info(),
+                        to: integrator_token.to_account_info(),
+                        authority: custodian.to_account_info(),
+                    },
+                    &[Custodian::SIGNER_SEEDS],
+                ),
+                fee,
+            )?;
+
+            amount = amount.saturating_sub(fee);
+
+            Some(IntegratorFeePaid {
+                prepared_order: prepared_order.key(),
+                integrator_token: integrator_token.key(),
+                fee_bps: integrator_fee.fee_bps,
+                amount: fee,
+            })
+        }
+        None => None,
+    };
+
+    // Pay the keeper who placed this order.
+    if let Some((keeper_token, keeper_tip)) = keeper_tip {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: prepared_custody_token.to_account_info(),
+                    to: keeper_token,
+                    authority: custodian.to_account_info(),
+                },
+                &[Custodian::SIGNER_SEEDS],
+            ),
+            keeper_tip,
+        )?;
+
+        amount = amount.saturating_sub(keeper_tip);
+    }
 
-    let custodian = &ctx.accounts.custodian;
-    let payer = &ctx.accounts.payer;
-    let prepared_order = &ctx.accounts.prepared_order;
-    let prepared_custody_token = &ctx.accounts.prepared_custody_token;
-    let token_program = &ctx.accounts.token_program;
-    let system_program = &ctx.accounts.system_program;
-    let target_router_endpoint = &ctx.accounts.target_router_endpoint;
+    // Pay the relayer who placed this order.
+    let relayer_fee = prepared_order.relayer_fee;
+    if relayer_fee > 0 {
+        let relayer_token = accounts
+            .relayer_token
+            .as_ref()
+            .ok_or_else(|| TokenRouterError::RelayerTokenRequired)?;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: prepared_custody_token.to_account_info(),
+                    to: relayer_token.to_account_info(),
+                    authority: custodian.to_account_info(),
+                },
+                &[Custodian::SIGNER_SEEDS],
+            ),
+            relayer_fee,
+        )?;
+
+        amount = amount.saturating_sub(relayer_fee);
+    }
 
-    let order_info = &ctx.accounts.prepared_order.info;
+    // The Fill does not encode a min amount out. CCTP mints exactly the burned amount on the
+    // destination, so the min amount out is honoured as long as enough is burned here.
+    if let Some(min_amount_out) = order_info.order_type.min_amount_out() {
+        require!(
+            amount >= min_amount_out,
+            TokenRouterError::MinAmountOutNotMet
+        );
+    }
+
+    let order_placed = OrderPlaced {
+        prepared_order: prepared_order.key(),
+        order_sender: order_info.order_sender,
+        amount,
+        target_chain: order_info.target_chain,
+        redeemer: order_info.redeemer,
+        fast: false,
+    };
 
     // This returns the CCTP nonce, but we do not need it.
     wormhole_cctp_solana::cpi::burn_and_publish(
         CpiContext::new_with_signer(
-            ctx.accounts
-                .token_messenger_minter_program
-                .to_account_info(),
+            accounts.token_messenger_minter_program.to_account_info(),
             wormhole_cctp_solana::cpi::DepositForBurnWithCaller {
                 burn_token_owner: custodian.to_account_info(),
                 payer: payer.to_account_info(),
-                token_messenger_minter_sender_authority: ctx
-                    .accounts
+                token_messenger_minter_sender_authority: accounts
                     .token_messenger_minter_sender_authority
                     .to_account_info(),
                 burn_token: prepared_custody_token.to_account_info(),
-                message_transmitter_config: ctx
-                    .accounts
-                    .message_transmitter_config
-                    .to_account_info(),
-                token_messenger: ctx.accounts.token_messenger.to_account_info(),
-                remote_token_messenger: ctx.accounts.remote_token_messenger.to_account_info(),
-                token_minter: ctx.accounts.token_minter.to_account_info(),
-                local_token: ctx.accounts.local_token.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-                cctp_message: ctx.accounts.cctp_message.to_account_info(),
-                message_transmitter_program: ctx
-                    .accounts
-                    .message_transmitter_program
-                    .to_account_info(),
-                token_messenger_minter_program: ctx
-                    .accounts
+                message_transmitter_config: accounts.message_transmitter_config.to_account_info(),
+                token_messenger: accounts.token_messenger.to_account_info(),
+                remote_token_messenger: accounts.remote_token_messenger.to_account_info(),
+                token_minter: accounts.token_minter.to_account_info(),
+                local_token: accounts.local_token.to_account_info(),
+                mint: accounts.mint.to_account_info(),
+                cctp_message: accounts.cctp_message.to_account_info(),
+                message_transmitter_program: accounts.message_transmitter_program.to_account_info(),
+                token_messenger_minter_program: accounts
                     .token_messenger_minter_program
                     .to_account_info(),
                 token_program: token_program.to_account_info(),
                 system_program: system_program.to_account_info(),
-                event_authority: ctx
-                    .accounts
+                event_authority: accounts
                     .token_messenger_minter_event_authority
                     .to_account_info(),
             },
@@ -229,29 +496,29 @@ fn handle_place_market_order_cctp(
                 &[
                     common::CCTP_MESSAGE_SEED_PREFIX,
                     prepared_order.key().as_ref(),
-                    &[ctx.bumps.cctp_message],
+                    &[bumps.cctp_message],
                 ],
             ],
         ),
         CpiContext::new_with_signer(
-            ctx.accounts.core_bridge_program.to_account_info(),
+            accounts.core_bridge_program.to_account_info(),
             wormhole_cctp_solana::cpi::PostMessage {
                 payer: payer.to_account_info(),
-                message: ctx.accounts.core_message.to_account_info(),
+                message: accounts.core_message.to_account_info(),
                 emitter: custodian.to_account_info(),
-                config: ctx.accounts.core_bridge_config.to_account_info(),
-                emitter_sequence: ctx.accounts.core_emitter_sequence.to_account_info(),
-                fee_collector: ctx.accounts.core_fee_collector.to_account_info(),
+                config: accounts.core_bridge_config.to_account_info(),
+                emitter_sequence: accounts.core_emitter_sequence.to_account_info(),
+                fee_collector: accounts.core_fee_collector.to_account_info(),
                 system_program: system_program.to_account_info(),
-                clock: ctx.accounts.clock.to_account_info(),
-                rent: ctx.accounts.rent.to_account_info(),
+                clock: accounts.clock.to_account_info(),
+                rent: accounts.rent.to_account_info(),
             },
             &[
                 Custodian::SIGNER_SEEDS,
                 &[
                     common::CORE_MESSAGE_SEED_PREFIX,
                     prepared_order.key().as_ref(),
-                    &[ctx.bumps.core_message],
+                    &[bumps.core_message],
                 ],
             ],
         ),
@@ -259,7 +526,7 @@ fn handle_place_market_order_cctp(
             burn_source: order_info.src_token.into(),
             destination_caller: target_router_endpoint.address,
             destination_cctp_domain,
-            amount: prepared_custody_token.amount,
+            amount,
             mint_recipient: target_router_endpoint.mint_recipient,
             wormhole_message_nonce: common::WORMHOLE_MESSAGE_NONCE,
             payload: common::messages::Fill {
@@ -279,9 +546,11 @@ fn handle_place_market_order_cctp(
         token_program.to_account_info(),
         token::CloseAccount {
             account: prepared_custody_token.to_account_info(),
-            destination: ctx.accounts.prepared_by.to_account_info(),
+            destination: accounts.prepared_by.to_account_info(),
             authority: custodian.to_account_info(),
         },
         &[Custodian::SIGNER_SEEDS],
-    ))
+    ))?;
+
+    Ok((order_placed, integrator_fee_paid))
 }
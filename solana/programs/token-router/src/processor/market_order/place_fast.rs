@@ -0,0 +1,385 @@
+use crate::{
+    composite::*,
+    error::TokenRouterError,
+    events::{IntegratorFeePaid, OrderPlaced},
+    state::{Custodian, FastOrderParameters, OrderType, PreparedOrder},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::{
+    wormhole_cctp_solana::wormhole::core_bridge_program, wormhole_io::TypePrefixedPayload,
+};
+
+/// Accounts required for [place_fast_market_order].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PlaceFastMarketOrder<'info> {
+    /// This account must be the same pubkey as the one who prepared the order.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: This account must equal the prepared order's `prepared_by` pubkey.
+    #[account(
+        mut,
+        address = prepared_order.prepared_by
+    )]
+    prepared_by: UncheckedAccount<'info>,
+
+    #[account(constraint = !custodian.paused @ TokenRouterError::Paused)]
+    custodian: CheckedCustodian<'info>,
+
+    #[account(
+        mut,
+        close = prepared_by,
+        constraint = {
+            let parameters = match prepared_order.order_type {
+                OrderType::FastMarket { parameters, .. } => parameters,
+                _ => return err!(TokenRouterError::InvalidOrderType),
+            };
+
+            require!(
+                !prepared_order.is_expired(Clock::get()?.slot),
+                TokenRouterError::OrderExpired
+            );
+
+            // Check to see if the deadline has expired. The Matching Engine would not be able to
+            // start an auction for this order.
+            require!(
+                parameters.deadline == 0
+                    || Clock::get()?.unix_timestamp < parameters.deadline.into(),
+                TokenRouterError::FastMarketOrderExpired,
+            );
+
+            true
+        }
+    )]
+    prepared_order: Box<Account<'info, PreparedOrder>>,
+
+    /// Temporary custody token account. This account will be closed at the end of this instruction.
+    ///
+    /// CHECK: Mutable. Seeds must be \["prepared-custody", prepared_order.key()\].
+    #[account(
+        mut,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+        ],
+        bump = prepared_order.prepared_custody_token_bump,
+    )]
+    prepared_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(
+        constraint = {
+            require_eq!(
+                target_router_endpoint.chain,
+                prepared_order.target_chain,
+                TokenRouterError::InvalidTargetRouter,
+            );
+
+            true
+        }
+    )]
+    target_router_endpoint: RegisteredEndpoint<'info>,
+
+    /// CHECK: Seeds must be \["emitter"\] (Matching Engine program).
+    #[account(
+        seeds = [matching_engine::state::Custodian::SEED_PREFIX],
+        bump = matching_engine::state::Custodian::BUMP,
+        seeds::program = matching_engine::id(),
+    )]
+    matching_engine_custodian: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \["Bridge"\] (Wormhole Core Bridge program).
+    #[account(mut)]
+    core_bridge_config: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. Seeds must be \["core-msg", prepared_order.key()\].
+    #[account(
+        mut,
+        seeds = [
+            common::CORE_MESSAGE_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+        ],
+        bump,
+    )]
+    core_message: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. Seeds must be \["finalized-core-msg", prepared_order.key()\].
+    #[account(
+        mut,
+        seeds = [
+            crate::FINALIZED_CORE_MESSAGE_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+        ],
+        bump,
+    )]
+    finalized_core_message: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. Seeds must be \["Sequence"\, custodian] (Wormhole Core Bridge program).
+    #[account(
+        mut,
+        seeds = [
+            b"Sequence",
+            custodian.key().as_ref(),
+        ],
+        bump,
+        seeds::program = core_bridge_program::id(),
+    )]
+    core_emitter_sequence: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \["fee_collector"\] (Wormhole Core Bridge program).
+    #[account(mut)]
+    core_fee_collector: UncheckedAccount<'info>,
+
+    /// Custody token account owned by the Matching Engine custodian. The Matching Engine moves
+    /// these funds when it prepares the order response for the fast market order. The sequence of
+    /// the finalized Wormhole message published by this instruction is encoded in this account's
+    /// seeds.
+    ///
+    /// CHECK: Seeds must be \["local-order-custody", sequence.to_be_bytes()\].
+    #[account(
+        init,
+        payer = payer,
+        token::mint = usdc,
+        token::authority = matching_engine_custodian,
+        seeds = [
+            common::LOCAL_ORDER_CUSTODY_TOKEN_SEED_PREFIX,
+            &next_message_sequence(&core_emitter_sequence).to_be_bytes(),
+        ],
+        bump,
+    )]
+    local_order_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    usdc: Usdc<'info>,
+
+    /// CHECK: Must equal Wormhole Core Bridge program ID.
+    #[account(address = core_bridge_program::id())]
+    core_bridge_program: UncheckedAccount<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+
+    /// CHECK: Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.
+    #[account(address = solana_program::sysvar::clock::id())]
+    clock: UncheckedAccount<'info>,
+
+    /// CHECK: Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.
+    #[account(address = solana_program::sysvar::rent::id())]
+    rent: UncheckedAccount<'info>,
+
+    /// Token account where the integrator fee is paid. Required if the prepared order has an
+    /// integrator fee.
+    #[account(
+        mut,
+        token::mint = common::USDC_MINT,
+        constraint = {
+            require!(
+                prepared_order
+                    .integrator_fee
+                    .is_some_and(|integrator_fee| integrator_fee.token == integrator_token.key()),
+                TokenRouterError::InvalidIntegratorToken
+            );
+
+            true
+        }
+    )]
+    integrator_token: Option<Box<Account<'info, token::TokenAccount>>>,
+}
+
+/// This instruction publishes a `FastMarketOrder` to be auctioned by the Matching Engine.
+///
+/// Orders placed on other chains burn their funds via CCTP to the Matching Engine, which is how
+/// the Matching Engine is paid out once the slow (finalized) message arrives. CCTP cannot mint
+/// within Solana's own domain, so the order's funds are instead deposited into a custody token
+/// account owned by the Matching Engine. Two Wormhole messages are published, mirroring the CCTP
+/// flow:
+/// * The same `FastMarketOrder` payload with finalized commitment. This message takes the place of
+///   the slow order response and its sequence is encoded in the custody token account's seeds.
+///   The Matching Engine only releases the deposited funds once this message is finalized.
+/// * The `FastMarketOrder` with confirmed commitment, which lets an auction start before
+///   finality.
+pub fn place_fast_market_order(ctx: Context<PlaceFastMarketOrder>) -> Result<()> {
+    let redeemer_message = std::mem::take(&mut ctx.accounts.prepared_order.redeemer_message);
+
+    let custodian = &ctx.accounts.custodian;
+    let prepared_order = &ctx.accounts.prepared_order;
+    let prepared_custody_token = &ctx.accounts.prepared_custody_token;
+    let token_program = &ctx.accounts.token_program;
+
+    let order_info = &prepared_order.info;
+    let (min_amount_out, parameters) = match order_info.order_type {
+        OrderType::FastMarket {
+            min_amount_out,
+            parameters,
+        } => (min_amount_out, parameters),
+        _ => return err!(TokenRouterError::InvalidOrderType),
+    };
+    let FastOrderParameters {
+        max_fee,
+        init_auction_fee,
+        deadline,
+    } = parameters;
+
+    let mut amount_in = prepared_custody_token.amount;
+
+    // Pay the integrator who routed this order. The fast market order is published with the
+    // remaining amount.
+    let integrator_fee_paid = match prepared_order.integrator_fee {
+        Some(integrator_fee) => {
+            let integrator_token = ctx
+                .accounts
+                .integrator_token
+                .as_ref()
+                .ok_or_else(|| TokenRouterError::InvalidIntegratorToken)?;
+            let fee = integrator_fee.amount(amount_in);
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: prepared_custody_token.to_account_info(),
+                        to: integrator_token.to_account_info(),
+                        authority: custodian.to_account_info(),
+                    },
+                    &[Custodian::SIGNER_SEEDS],
+                ),
+                fee,
+            )?;
+
+            amount_in = amount_in.saturating_sub(fee);
+
+            Some(IntegratorFeePaid {
+                prepared_order: prepared_order.key(),
+                integrator_token: integrator_token.key(),
+                fee_bps: integrator_fee.fee_bps,
+                amount: fee,
+            })
+        }
+        None => None,
+    };
+
+    let order_placed = OrderPlaced {
+        prepared_order: prepared_order.key(),
+        order_sender: order_info.order_sender,
+        amount: amount_in,
+        target_chain: order_info.target_chain,
+        redeemer: order_info.redeemer,
+        fast: true,
+    };
+
+    // Deposit the order's funds for the Matching Engine.
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: prepared_custody_token.to_account_info(),
+                to: ctx.accounts.local_order_custody_token.to_account_info(),
+                authority: custodian.to_account_info(),
+            },
+            &[Custodian::SIGNER_SEEDS],
+        ),
+        amount_in,
+    )?;
+
+    let payload = common::messages::FastMarketOrder {
+        amount_in,
+        min_amount_out: min_amount_out.unwrap_or_default(),
+        target_chain: order_info.target_chain,
+        redeemer: order_info.redeemer,
+        sender: order_info.order_sender.to_bytes(),
+        refund_address: order_info.refund_token.to_bytes(),
+        max_fee,
+        init_auction_fee,
+        deadline,
+        redeemer_message: redeemer_message
+            .try_into()
+            .map_err(|_| TokenRouterError::RedeemerMessageTooLarge)?,
+    }
+    .to_vec();
+
+    // The finalized message must be published first so that the fast message's sequence is the
+    // next one. The Matching Engine relies on this to reconcile the two messages.
+    post_message(
+        ctx.accounts,
+        &ctx.accounts.finalized_core_message,
+        &[
+            crate::FINALIZED_CORE_MESSAGE_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+            &[ctx.bumps.finalized_core_message],
+        ],
+        payload.clone(),
+        core_bridge_program::Commitment::Finalized,
+    )?;
+    post_message(
+        ctx.accounts,
+        &ctx.accounts.core_message,
+        &[
+            common::CORE_MESSAGE_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+            &[ctx.bumps.core_message],
+        ],
+        payload,
+        core_bridge_program::Commitment::Confirmed,
+    )?;
+
+    // Finally close token account.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: prepared_custody_token.to_account_info(),
+            destination: ctx.accounts.prepared_by.to_account_info(),
+            authority: custodian.to_account_info(),
+        },
+        &[Custodian::SIGNER_SEEDS],
+    ))?;
+
+    if let Some(event) = integrator_fee_paid {
+        emit_cpi!(event);
+    }
+    emit_cpi!(order_placed);
+
+    // Done.
+    Ok(())
+}
+
+/// Sequence of the next Wormhole message published by the custodian, which is the value found in
+/// the emitter sequence account. This account does not exist before the first message.
+fn next_message_sequence(core_emitter_sequence: &AccountInfo) -> u64 {
+    core_emitter_sequence
+        .try_borrow_data()
+        .ok()
+        .and_then(|data| data.get(..8).and_then(|value| value.try_into().ok()))
+        .map(u64::from_le_bytes)
+        .unwrap_or_default()
+}
+
+fn post_message<'info>(
+    accounts: &PlaceFastMarketOrder<'info>,
+    message: &UncheckedAccount<'info>,
+    message_seeds: &[&[u8]],
+    payload: Vec<u8>,
+    commitment: core_bridge_program::Commitment,
+) -> Result<()> {
+    core_bridge_program::cpi::post_message(
+        CpiContext::new_with_signer(
+            accounts.core_bridge_program.to_account_info(),
+            core_bridge_program::cpi::PostMessage {
+                payer: accounts.payer.to_account_info(),
+                message: message.to_account_info(),
+                emitter: accounts.custodian.to_account_info(),
+                config: accounts.core_bridge_config.to_account_info(),
+                emitter_sequence: accounts.core_emitter_sequence.to_account_info(),
+                fee_collector: accounts.core_fee_collector.to_account_info(),
+                system_program: accounts.system_program.to_account_info(),
+                clock: accounts.clock.to_account_info(),
+                rent: accounts.rent.to_account_info(),
+            },
+            &[Custodian::SIGNER_SEEDS, message_seeds],
+        ),
+        core_bridge_program::cpi::PostMessageArgs {
+            nonce: common::WORMHOLE_MESSAGE_NONCE,
+            payload,
+            commitment,
+        },
+    )
+}
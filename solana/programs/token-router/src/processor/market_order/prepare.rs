@@ -1,7 +1,11 @@
 use crate::{
     composite::*,
     error::TokenRouterError,
-    state::{OrderType, PreparedOrder, PreparedOrderInfo},
+    events::OrderPrepared,
+    state::{
+        FastOrderParameters, IntegratorFee, OrderType, PreparedOrder, PreparedOrderInfo,
+        PriceCondition, PriceTrigger,
+    },
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -9,6 +13,7 @@ use common::TRANSFER_AUTHORITY_SEED_PREFIX;
 use solana_program::keccak;
 
 /// Accounts required for [prepare_market_order].
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: PrepareMarketOrderArgs)]
 pub struct PrepareMarketOrder<'info> {
@@ -67,10 +72,71 @@ pub struct PrepareMarketOrder<'info> {
                 TokenRouterError::RedeemerMessageTooLarge
             );
 
-            // If provided, validate min amount out.
+            // If provided, the fast order fees must be covered by the amount in.
+            if let Some(FastOrderParameters {
+                max_fee,
+                init_auction_fee,
+                ..
+            }) = args.fast_order_parameters
+            {
+                let fees = max_fee
+                    .checked_add(init_auction_fee)
+                    .ok_or_else(|| TokenRouterError::U64Overflow)?;
+                require!(fees < args.amount_in, TokenRouterError::FastOrderFeesTooHigh);
+            }
+
+            // If provided, the trigger's keeper tip must be covered by the amount in. A triggered
+            // order cannot also be a fast market order.
+            if let Some(trigger) = &args.trigger {
+                require!(
+                    args.fast_order_parameters.is_none(),
+                    TokenRouterError::InvalidOrderType
+                );
+                require!(
+                    trigger.keeper_tip() < args.amount_in,
+                    TokenRouterError::KeeperTipTooHigh
+                );
+            }
+
+            // If provided, the relayer fee must be covered by the amount in along with the keeper
+            // tip. Fast market orders are not placed via CCTP, so they cannot pay a relayer fee.
+            if args.relayer_fee.is_some() {
+                require!(
+                    args.fast_order_parameters.is_none(),
+                    TokenRouterError::InvalidOrderType
+                );
+                require!(
+                    args.max_fees() < args.amount_in,
+                    TokenRouterError::RelayerFeeTooHigh
+                );
+            }
+
+            // If provided, the integrator fee must not exceed the maximum set by the owner and must
+            // be covered by the amount in along with the other fees.
+            if let Some(integrator_fee) = &args.integrator_fee {
+                require!(
+                    integrator_fee.fee_bps <= custodian.max_integrator_fee_bps,
+                    TokenRouterError::IntegratorFeeTooHigh
+                );
+                require!(
+                    args.max_fees() < args.amount_in,
+                    TokenRouterError::IntegratorFeeTooHigh
+                );
+            }
+
+            // If provided, the order must not already be expired.
+            if let Some(expiry_slot) = args.expiry_slot {
+                require!(
+                    expiry_slot >= Clock::get()?.slot,
+                    TokenRouterError::InvalidExpirySlot
+                );
+            }
+
+            // If provided, validate min amount out against the least amount this order can deliver
+            // once fees are taken out of the amount in.
             if let Some(min_amount_out) = args.min_amount_out {
                 require!(
-                    min_amount_out <= args.amount_in,
+                    min_amount_out <= args.amount_in.saturating_sub(args.max_fees()),
                     TokenRouterError::MinAmountOutTooHigh,
                 );
             }
@@ -141,7 +207,9 @@ pub struct PrepareMarketOrderArgs {
     pub amount_in: u64,
 
     /// If provided, minimum amount of tokens to receive in exchange for
-    /// [amount_in](Self::amount_in).
+    /// [amount_in](Self::amount_in). Orders transferred via CCTP cannot be placed if less would be
+    /// burned. Fast market orders encode it in the `FastMarketOrder`, so the Matching Engine
+    /// rejects auction offers that would deliver less.
     pub min_amount_out: Option<u64>,
 
     /// The Wormhole chain ID of the network to transfer tokens to.
@@ -153,25 +221,167 @@ pub struct PrepareMarketOrderArgs {
     /// Arbitrary payload to be sent to the [redeemer](Self::redeemer), which can be used to encode
     /// instructions or data for another network's smart contract.
     pub redeemer_message: Vec<u8>,
+
+    /// If provided, the order is placed as a fast market order auctioned by the Matching Engine
+    /// (see [place_fast_market_order](crate::place_fast_market_order)).
+    pub fast_order_parameters: Option<FastOrderParameters>,
+
+    /// If provided, the order can only be placed by a keeper once the trigger condition is met.
+    pub trigger: Option<OrderTrigger>,
+
+    /// If provided, slot after which the order can no longer be placed and anyone can refund it.
+    pub expiry_slot: Option<u64>,
+
+    /// If provided, fee paid out of [amount_in](Self::amount_in) to the relayer who places the
+    /// order via CCTP. This allows a relayer to pay rent and the Wormhole message fee on behalf of
+    /// a sender without SOL.
+    pub relayer_fee: Option<u64>,
+
+    /// If provided, fee paid out of [amount_in](Self::amount_in) to the integrator who routed the
+    /// order, which is skimmed when the order is placed.
+    pub integrator_fee: Option<IntegratorFee>,
+}
+
+/// Condition for a keeper to place an order, who is paid the keeper tip out of the amount in.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub enum OrderTrigger {
+    /// See [place_limit_order_cctp](crate::place_limit_order_cctp).
+    Limit {
+        condition: PriceCondition,
+        keeper_tip: u64,
+    },
+    /// See [place_time_locked_order_cctp](crate::place_time_locked_order_cctp).
+    TimeLocked { unlock_slot: u64, keeper_tip: u64 },
+}
+
+impl OrderTrigger {
+    pub fn keeper_tip(&self) -> u64 {
+        match self {
+            Self::Limit { keeper_tip, .. } | Self::TimeLocked { keeper_tip, .. } => *keeper_tip,
+        }
+    }
+
+    /// Encoding of this trigger in [PrepareMarketOrderArgs::hash], which starts with the trigger's
+    /// variant so each variant has a fixed length.
+    fn hash_data(&self) -> Vec<u8> {
+        match self {
+            Self::Limit {
+                condition:
+                    PriceCondition {
+                        oracle,
+                        price,
+                        trigger,
+                    },
+                keeper_tip,
+            } => {
+                let trigger: u8 = match trigger {
+                    PriceTrigger::AtOrAbove => 0,
+                    PriceTrigger::AtOrBelow => 1,
+                };
+                let data: [&[u8]; 5] = [
+                    &[0],
+                    oracle.as_ref(),
+                    &price.to_be_bytes(),
+                    &[trigger],
+                    &keeper_tip.to_be_bytes(),
+                ];
+                data.concat()
+            }
+            Self::TimeLocked {
+                unlock_slot,
+                keeper_tip,
+            } => {
+                let data: [&[u8]; 3] =
+                    [&[1], &unlock_slot.to_be_bytes(), &keeper_tip.to_be_bytes()];
+                data.concat()
+            }
+        }
+    }
 }
 
 impl PrepareMarketOrderArgs {
+    /// Most this order can pay in fees out of [amount_in](Self::amount_in), which is the max fee
+    /// and init auction fee of a fast market order or the keeper tip of a triggered order, plus the
+    /// relayer and integrator fees.
+    pub fn max_fees(&self) -> u64 {
+        let order_fees = match (&self.fast_order_parameters, &self.trigger) {
+            (Some(parameters), _) => parameters
+                .max_fee
+                .saturating_add(parameters.init_auction_fee),
+            (None, Some(trigger)) => trigger.keeper_tip(),
+            (None, None) => 0,
+        };
+
+        let integrator_fee = self
+            .integrator_fee
+            .map(|integrator_fee| integrator_fee.amount(self.amount_in))
+            .unwrap_or_default();
+
+        order_fees
+            .saturating_add(self.relayer_fee.unwrap_or_default())
+            .saturating_add(integrator_fee)
+    }
+
+    /// Hash of these arguments, which seeds the program transfer authority. Every field is framed
+    /// so that no two sets of arguments share an encoding: the redeemer message is prefixed with
+    /// its length and each optional field is prefixed with whether it is present.
     pub fn hash(&self) -> keccak::Hash {
-        match self.min_amount_out {
-            Some(min_amount_out) => keccak::hashv(&[
-                &self.amount_in.to_be_bytes(),
-                &min_amount_out.to_be_bytes(),
-                &self.target_chain.to_be_bytes(),
-                &self.redeemer,
-                &self.redeemer_message,
-            ]),
-            None => keccak::hashv(&[
-                &self.amount_in.to_be_bytes(),
-                &self.target_chain.to_be_bytes(),
-                &self.redeemer,
-                &self.redeemer_message,
-            ]),
+        let mut data = Vec::new();
+        data.extend_from_slice(&self.amount_in.to_be_bytes());
+        push_optional(&mut data, self.min_amount_out.map(u64::to_be_bytes));
+        data.extend_from_slice(&self.target_chain.to_be_bytes());
+        data.extend_from_slice(&self.redeemer);
+
+        // The redeemer message's length cannot exceed u32::MAX because it must fit in a
+        // transaction.
+        let redeemer_message_len = u32::try_from(self.redeemer_message.len())
+            .unwrap_or_else(|_| u32::MAX)
+            .to_be_bytes();
+        data.extend_from_slice(&redeemer_message_len);
+        data.extend_from_slice(&self.redeemer_message);
+
+        push_optional(
+            &mut data,
+            self.fast_order_parameters.map(
+                |FastOrderParameters {
+                     max_fee,
+                     init_auction_fee,
+                     deadline,
+                 }| {
+                    [
+                        max_fee.to_be_bytes().as_slice(),
+                        &init_auction_fee.to_be_bytes(),
+                        &deadline.to_be_bytes(),
+                    ]
+                    .concat()
+                },
+            ),
+        );
+        push_optional(
+            &mut data,
+            self.trigger.as_ref().map(OrderTrigger::hash_data),
+        );
+        push_optional(&mut data, self.expiry_slot.map(u64::to_be_bytes));
+        push_optional(&mut data, self.relayer_fee.map(u64::to_be_bytes));
+        push_optional(
+            &mut data,
+            self.integrator_fee.map(|IntegratorFee { token, fee_bps }| {
+                [token.as_ref(), &fee_bps.to_be_bytes()].concat()
+            }),
+        );
+
+        keccak::hash(&data)
+    }
+}
+
+/// Append an optional field to a hashed encoding as a presence byte followed by its value.
+fn push_optional(data: &mut Vec<u8>, value: Option<impl AsRef<[u8]>>) {
+    match value {
+        Some(value) => {
+            data.push(1);
+            data.extend_from_slice(value.as_ref());
         }
+        None => data.push(0),
     }
 }
 
@@ -187,8 +397,44 @@ pub fn prepare_market_order(
         target_chain,
         redeemer,
         redeemer_message,
+        fast_order_parameters,
+        trigger,
+        expiry_slot,
+        relayer_fee,
+        integrator_fee,
     } = args;
 
+    let order_type = match (fast_order_parameters, trigger) {
+        (None, None) => OrderType::Market { min_amount_out },
+        (Some(parameters), None) => OrderType::FastMarket {
+            min_amount_out,
+            parameters,
+        },
+        (
+            None,
+            Some(OrderTrigger::Limit {
+                condition,
+                keeper_tip,
+            }),
+        ) => OrderType::Limit {
+            min_amount_out,
+            condition,
+            keeper_tip,
+        },
+        (
+            None,
+            Some(OrderTrigger::TimeLocked {
+                unlock_slot,
+                keeper_tip,
+            }),
+        ) => OrderType::TimeLocked {
+            min_amount_out,
+            unlock_slot,
+            keeper_tip,
+        },
+        (Some(_), Some(_)) => return err!(TokenRouterError::InvalidOrderType),
+    };
+
     let token_program = &ctx.accounts.token_program;
     let sender_token = &ctx.accounts.sender_token;
     let custody_token = &ctx.accounts.prepared_custody_token;
@@ -243,12 +489,22 @@ pub fn prepare_market_order(
         _ => return err!(TokenRouterError::EitherSenderOrProgramTransferAuthority),
     };
 
+    let order_prepared = OrderPrepared {
+        prepared_order: prepared_order_key,
+        order_sender,
+        prepared_by: ctx.accounts.payer.key(),
+        order_type: order_type.clone(),
+        amount_in,
+        target_chain,
+        redeemer,
+    };
+
     // Set the values in prepared order account.
     prepared_order.set_inner(PreparedOrder {
         info: PreparedOrderInfo {
             order_sender,
             prepared_by: ctx.accounts.payer.key(),
-            order_type: OrderType::Market { min_amount_out },
+            order_type,
             src_token: sender_token.key(),
             refund_token: refund_token.key(),
             target_chain,
@@ -256,8 +512,86 @@ pub fn prepare_market_order(
             prepared_custody_token_bump: ctx.bumps.prepared_custody_token,
         },
         redeemer_message,
+        expiry_slot,
+        relayer_fee: relayer_fee.unwrap_or_default(),
+        integrator_fee,
     });
 
+    emit_cpi!(order_prepared);
+
     // Done.
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args() -> PrepareMarketOrderArgs {
+        PrepareMarketOrderArgs {
+            amount_in: 69,
+            min_amount_out: None,
+            target_chain: 2,
+            redeemer: [1; 32],
+            redeemer_message: b"all your base".to_vec(),
+            fast_order_parameters: None,
+            trigger: None,
+            expiry_slot: None,
+            relayer_fee: None,
+            integrator_fee: None,
+        }
+    }
+
+    #[test]
+    fn hash_distinguishes_swapped_optional_fields() {
+        let expiry = PrepareMarketOrderArgs {
+            expiry_slot: Some(420),
+            ..args()
+        };
+        let relayer_fee = PrepareMarketOrderArgs {
+            relayer_fee: Some(420),
+            ..args()
+        };
+        assert_ne!(expiry.hash(), relayer_fee.hash());
+
+        let min_amount_out = PrepareMarketOrderArgs {
+            min_amount_out: Some(420),
+            ..args()
+        };
+        assert_ne!(min_amount_out.hash(), expiry.hash());
+        assert_ne!(min_amount_out.hash(), relayer_fee.hash());
+    }
+
+    #[test]
+    fn hash_distinguishes_shifted_redeemer_message() {
+        let relayer_fee = PrepareMarketOrderArgs {
+            relayer_fee: Some(420),
+            ..args()
+        };
+
+        // Move the relayer fee's bytes to the end of the redeemer message.
+        let mut redeemer_message = args().redeemer_message;
+        redeemer_message.extend_from_slice(&420u64.to_be_bytes());
+        let shifted = PrepareMarketOrderArgs {
+            redeemer_message,
+            ..args()
+        };
+        assert_ne!(relayer_fee.hash(), shifted.hash());
+
+        let trigger = OrderTrigger::TimeLocked {
+            unlock_slot: 420,
+            keeper_tip: 1,
+        };
+        let triggered = PrepareMarketOrderArgs {
+            trigger: Some(trigger),
+            ..args()
+        };
+        let mut redeemer_message = args().redeemer_message;
+        redeemer_message.extend_from_slice(&trigger.hash_data());
+        let shifted = PrepareMarketOrderArgs {
+            redeemer_message,
+            ..args()
+        };
+        assert_ne!(triggered.hash(), shifted.hash());
+    }
+}
@@ -7,8 +7,17 @@ pub use close_prepared_order::*;
 mod consume_prepared_fill;
 pub use consume_prepared_fill::*;
 
+mod forward_expired_prepared_fill;
+pub use forward_expired_prepared_fill::*;
+
 mod market_order;
 pub use market_order::*;
 
 mod redeem_fill;
 pub use redeem_fill::*;
+
+mod refund_expired_order;
+pub use refund_expired_order::*;
+
+mod update_price_oracle;
+pub use update_price_oracle::*;
@@ -1,6 +1,7 @@
 use crate::{
     composite::*,
     error::TokenRouterError,
+    events::FillRedeemed,
     state::{Custodian, FillType, PreparedFill, PreparedFillInfo, PreparedFillSeeds},
 };
 use anchor_lang::prelude::*;
@@ -11,12 +12,13 @@ use common::{
         self,
         cctp::{message_transmitter_program, token_messenger_minter_program},
         cpi::ReceiveMessageArgs,
+        wormhole::VaaAccount,
     },
 };
 
 #[derive(Accounts)]
-struct CctpReceiveMessage<'info> {
-    mint_recipient: CctpMintRecipientMut<'info>,
+pub(super) struct CctpReceiveMessage<'info> {
+    pub(super) mint_recipient: CctpMintRecipientMut<'info>,
 
     /// CHECK: Seeds must be \["message_transmitter_authority"\] (CCTP Message Transmitter program).
     message_transmitter_authority: UncheckedAccount<'info>,
@@ -69,7 +71,61 @@ struct CctpReceiveMessage<'info> {
     message_transmitter_program: UncheckedAccount<'info>,
 }
 
+impl<'info> CctpReceiveMessage<'info> {
+    /// Verify the fill VAA and mint the tokens encoded in the CCTP message to the mint recipient.
+    pub(super) fn verify_vaa_and_mint<'a>(
+        &self,
+        fill_vaa: &'a LiquidityLayerVaa<'info>,
+        payer: &Signer<'info>,
+        custodian: &CheckedCustodian<'info>,
+        token_program: &Program<'info, token::Token>,
+        system_program: &Program<'info, System>,
+        args: CctpMessageArgs,
+    ) -> Result<VaaAccount<'a>> {
+        wormhole_cctp_solana::cpi::verify_vaa_and_mint(
+            fill_vaa,
+            CpiContext::new_with_signer(
+                self.message_transmitter_program.to_account_info(),
+                message_transmitter_program::cpi::ReceiveTokenMessengerMinterMessage {
+                    payer: payer.to_account_info(),
+                    caller: custodian.to_account_info(),
+                    message_transmitter_authority: self
+                        .message_transmitter_authority
+                        .to_account_info(),
+                    message_transmitter_config: self.message_transmitter_config.to_account_info(),
+                    used_nonces: self.used_nonces.to_account_info(),
+                    token_messenger_minter_program: self
+                        .token_messenger_minter_program
+                        .to_account_info(),
+                    system_program: system_program.to_account_info(),
+                    message_transmitter_event_authority: self
+                        .message_transmitter_event_authority
+                        .to_account_info(),
+                    message_transmitter_program: self.message_transmitter_program.to_account_info(),
+                    token_messenger: self.token_messenger.to_account_info(),
+                    remote_token_messenger: self.remote_token_messenger.to_account_info(),
+                    token_minter: self.token_minter.to_account_info(),
+                    local_token: self.local_token.to_account_info(),
+                    token_pair: self.token_pair.to_account_info(),
+                    mint_recipient: self.mint_recipient.to_account_info(),
+                    custody_token: self.token_messenger_minter_custody_token.to_account_info(),
+                    token_program: token_program.to_account_info(),
+                    token_messenger_minter_event_authority: self
+                        .token_messenger_minter_event_authority
+                        .to_account_info(),
+                },
+                &[Custodian::SIGNER_SEEDS],
+            ),
+            ReceiveMessageArgs {
+                encoded_message: args.encoded_cctp_message,
+                attestation: args.cctp_attestation,
+            },
+        )
+    }
+}
+
 /// Accounts required for [redeem_cctp_fill].
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RedeemCctpFill<'info> {
     #[account(mut)]
@@ -161,67 +217,13 @@ pub fn redeem_cctp_fill(ctx: Context<RedeemCctpFill>, args: CctpMessageArgs) ->
 }
 
 fn handle_redeem_fill_cctp(ctx: Context<RedeemCctpFill>, args: CctpMessageArgs) -> Result<()> {
-    let vaa = wormhole_cctp_solana::cpi::verify_vaa_and_mint(
+    let vaa = ctx.accounts.cctp.verify_vaa_and_mint(
         &ctx.accounts.fill_vaa,
-        CpiContext::new_with_signer(
-            ctx.accounts
-                .cctp
-                .message_transmitter_program
-                .to_account_info(),
-            message_transmitter_program::cpi::ReceiveTokenMessengerMinterMessage {
-                payer: ctx.accounts.payer.to_account_info(),
-                caller: ctx.accounts.custodian.to_account_info(),
-                message_transmitter_authority: ctx
-                    .accounts
-                    .cctp
-                    .message_transmitter_authority
-                    .to_account_info(),
-                message_transmitter_config: ctx
-                    .accounts
-                    .cctp
-                    .message_transmitter_config
-                    .to_account_info(),
-                used_nonces: ctx.accounts.cctp.used_nonces.to_account_info(),
-                token_messenger_minter_program: ctx
-                    .accounts
-                    .cctp
-                    .token_messenger_minter_program
-                    .to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                message_transmitter_event_authority: ctx
-                    .accounts
-                    .cctp
-                    .message_transmitter_event_authority
-                    .to_account_info(),
-                message_transmitter_program: ctx
-                    .accounts
-                    .cctp
-                    .message_transmitter_program
-                    .to_account_info(),
-                token_messenger: ctx.accounts.cctp.token_messenger.to_account_info(),
-                remote_token_messenger: ctx.accounts.cctp.remote_token_messenger.to_account_info(),
-                token_minter: ctx.accounts.cctp.token_minter.to_account_info(),
-                local_token: ctx.accounts.cctp.local_token.to_account_info(),
-                token_pair: ctx.accounts.cctp.token_pair.to_account_info(),
-                mint_recipient: ctx.accounts.cctp.mint_recipient.to_account_info(),
-                custody_token: ctx
-                    .accounts
-                    .cctp
-                    .token_messenger_minter_custody_token
-                    .to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info(),
-                token_messenger_minter_event_authority: ctx
-                    .accounts
-                    .cctp
-                    .token_messenger_minter_event_authority
-                    .to_account_info(),
-            },
-            &[Custodian::SIGNER_SEEDS],
-        ),
-        ReceiveMessageArgs {
-            encoded_message: args.encoded_cctp_message,
-            attestation: args.cctp_attestation,
-        },
+        &ctx.accounts.payer,
+        &ctx.accounts.custodian,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        args,
     )?;
 
     // Wormhole CCTP deposit should be ours, so make sure this is a fill we recognize.
@@ -237,6 +239,16 @@ fn handle_redeem_fill_cctp(ctx: Context<RedeemCctpFill>, args: CctpMessageArgs)
         .unwrap()
         .to_fill_unchecked();
 
+    let fill_redeemed = FillRedeemed {
+        prepared_fill: ctx.accounts.prepared_fill.key(),
+        fill_source: ctx.accounts.fill_vaa.key(),
+        fill_type: FillType::WormholeCctpDeposit,
+        source_chain: fill.source_chain(),
+        order_sender: fill.order_sender(),
+        redeemer: Pubkey::from(fill.redeemer()),
+        amount,
+    };
+
     // Set prepared fill data.
     ctx.accounts.prepared_fill.set_inner(PreparedFill {
         seeds: PreparedFillSeeds {
@@ -267,7 +279,12 @@ fn handle_redeem_fill_cctp(ctx: Context<RedeemCctpFill>, args: CctpMessageArgs)
             &[Custodian::SIGNER_SEEDS],
         ),
         amount,
-    )
+    )?;
+
+    emit_cpi!(fill_redeemed);
+
+    // Done.
+    Ok(())
 }
 
 fn try_compute_prepared_fill_size(fill_vaa: &LiquidityLayerVaa) -> Result<usize> {
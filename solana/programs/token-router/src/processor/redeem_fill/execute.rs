@@ -0,0 +1,244 @@
+use super::cctp::*;
+use crate::{
+    composite::*,
+    error::TokenRouterError,
+    state::{AllowedRedeemer, Custodian},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::messages::raw::{LiquidityLayerDepositMessage, LiquidityLayerMessage, MessageToVec};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+
+/// Anchor selector of the `execute_fill` instruction, which allowed redeemer programs must
+/// implement.
+pub const EXECUTE_FILL_SELECTOR: [u8; 8] = [214, 179, 24, 216, 219, 100, 181, 21];
+
+/// Accounts required for [redeem_and_execute].
+#[derive(Accounts)]
+pub struct RedeemAndExecute<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    fill_vaa: LiquidityLayerVaa<'info>,
+
+    /// The redeemer encoded in the fill must be an allowed redeemer program.
+    #[account(
+        seeds = [
+            AllowedRedeemer::SEED_PREFIX,
+            allowed_redeemer.program_id.as_ref(),
+        ],
+        bump = allowed_redeemer.bump,
+        constraint = {
+            require_keys_eq!(
+                try_fill_redeemer(&fill_vaa)?,
+                allowed_redeemer.program_id,
+                TokenRouterError::InvalidRedeemer
+            );
+
+            true
+        }
+    )]
+    allowed_redeemer: Account<'info, AllowedRedeemer>,
+
+    /// CHECK: Must equal the allowed redeemer program ID.
+    #[account(address = allowed_redeemer.program_id)]
+    redeemer_program: UncheckedAccount<'info>,
+
+    /// Signs the `execute_fill` instruction so the redeemer program can verify that it was invoked
+    /// by this program. This PDA has no authority over any token account.
+    ///
+    /// CHECK: Seeds must be \["redeemer-authority", redeemer_program.key()\].
+    #[account(
+        seeds = [
+            AllowedRedeemer::AUTHORITY_SEED_PREFIX,
+            redeemer_program.key().as_ref(),
+        ],
+        bump,
+    )]
+    redeemer_authority: UncheckedAccount<'info>,
+
+    /// Token account controlled by the redeemer program, where the fill's tokens are transferred
+    /// before the redeemer program is invoked.
+    ///
+    /// CHECK: Mutable. Must equal the allowed redeemer's token account.
+    #[account(
+        mut,
+        address = allowed_redeemer.redeemer_token,
+    )]
+    redeemer_token: UncheckedAccount<'info>,
+
+    /// Registered emitter account representing a Circle Integration on another network.
+    ///
+    /// Seeds must be \["registered_emitter", target_chain.to_be_bytes()\].
+    #[account(
+        constraint = {
+            // Validate that this message originated from a registered emitter.
+            let emitter = fill_vaa.load_unchecked().emitter_info();
+            require_eq!(
+                emitter.chain,
+                source_router_endpoint.chain,
+                TokenRouterError::InvalidSourceRouter
+            );
+            require!(
+                emitter.address == source_router_endpoint.address,
+                TokenRouterError::InvalidSourceRouter
+            );
+
+            true
+        }
+    )]
+    source_router_endpoint: RegisteredEndpoint<'info>,
+
+    cctp: CctpReceiveMessage<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+/// Instruction data (following [EXECUTE_FILL_SELECTOR]) of the `execute_fill` instruction invoked
+/// on the redeemer program.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ExecuteFillArgs {
+    /// Wormhole chain ID reflecting where the order was created.
+    pub source_chain: u16,
+
+    /// Universal address of the order sender.
+    pub order_sender: [u8; 32],
+
+    /// Amount of tokens transferred to the redeemer token account.
+    pub amount: u64,
+
+    /// Message encoded in the fill by the order sender.
+    pub redeemer_message: Vec<u8>,
+}
+
+/// This instruction reconciles a Wormhole CCTP deposit message with a CCTP message like
+/// [redeem_cctp_fill](crate::redeem_cctp_fill). But instead of preparing a fill, the minted tokens
+/// are transferred to the allowed redeemer's token account and the redeemer program is invoked.
+///
+/// The redeemer program is invoked with the redeemer authority (signer), the redeemer token account
+/// and this instruction's remaining accounts. The custodian does not sign this invocation because
+/// it is the token authority of this program's custody accounts.
+pub fn redeem_and_execute<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedeemAndExecute<'info>>,
+    args: CctpMessageArgs,
+) -> Result<()> {
+    let execute_fill_args = {
+        let vaa = ctx.accounts.cctp.verify_vaa_and_mint(
+            &ctx.accounts.fill_vaa,
+            &ctx.accounts.payer,
+            &ctx.accounts.custodian,
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program,
+            args,
+        )?;
+
+        // Wormhole CCTP deposit should be ours, so make sure this is a fill we recognize.
+        let deposit = LiquidityLayerMessage::try_from(vaa.payload())
+            .unwrap()
+            .to_deposit_unchecked();
+
+        // This is safe because we know the amount is within u64 range.
+        let amount = u64::try_from(ruint::aliases::U256::from_be_bytes(deposit.amount())).unwrap();
+
+        // This operation is safe because we already validated the fill from the account context.
+        let fill = LiquidityLayerDepositMessage::try_from(deposit.payload())
+            .unwrap()
+            .to_fill_unchecked();
+
+        ExecuteFillArgs {
+            source_chain: fill.source_chain(),
+            order_sender: fill.order_sender(),
+            amount,
+            redeemer_message: fill.message_to_vec(),
+        }
+    };
+
+    let custodian = &ctx.accounts.custodian;
+    let redeemer_token = &ctx.accounts.redeemer_token;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.cctp.mint_recipient.to_account_info(),
+                to: redeemer_token.to_account_info(),
+                authority: custodian.to_account_info(),
+            },
+            &[Custodian::SIGNER_SEEDS],
+        ),
+        execute_fill_args.amount,
+    )?;
+
+    let redeemer_authority = &ctx.accounts.redeemer_authority;
+    let redeemer_program = &ctx.accounts.redeemer_program;
+
+    let mut accounts = Vec::with_capacity(ctx.remaining_accounts.len().saturating_add(2));
+    accounts.push(AccountMeta::new_readonly(redeemer_authority.key(), true));
+    accounts.push(AccountMeta::new(redeemer_token.key(), false));
+    accounts.extend(ctx.remaining_accounts.iter().map(|acc_info| {
+        if acc_info.is_writable {
+            AccountMeta::new(acc_info.key(), acc_info.is_signer)
+        } else {
+            AccountMeta::new_readonly(acc_info.key(), acc_info.is_signer)
+        }
+    }));
+
+    let mut data = EXECUTE_FILL_SELECTOR.to_vec();
+    execute_fill_args
+        .serialize(&mut data)
+        .map_err(|_| ErrorCode::InstructionDidNotSerialize)?;
+
+    let mut account_infos = Vec::with_capacity(ctx.remaining_accounts.len().saturating_add(3));
+    account_infos.push(redeemer_authority.to_account_info());
+    account_infos.push(redeemer_token.to_account_info());
+    account_infos.extend_from_slice(ctx.remaining_accounts);
+    account_infos.push(redeemer_program.to_account_info());
+
+    invoke_signed(
+        &Instruction {
+            program_id: redeemer_program.key(),
+            accounts,
+            data,
+        },
+        &account_infos,
+        &[&[
+            AllowedRedeemer::AUTHORITY_SEED_PREFIX,
+            redeemer_program.key().as_ref(),
+            &[ctx.bumps.redeemer_authority],
+        ]],
+    )
+    .map_err(Into::into)
+}
+
+fn try_fill_redeemer(fill_vaa: &LiquidityLayerVaa) -> Result<Pubkey> {
+    let vaa = fill_vaa.load_unchecked();
+    let msg = LiquidityLayerMessage::try_from(vaa.payload()).unwrap();
+
+    let deposit = msg
+        .deposit()
+        .ok_or_else(|| error!(TokenRouterError::InvalidPayloadId))?;
+    let msg = LiquidityLayerDepositMessage::try_from(deposit.payload())
+        .map_err(|_| TokenRouterError::InvalidDepositMessage)?;
+    let fill = msg
+        .fill()
+        .ok_or_else(|| TokenRouterError::InvalidDepositPayloadId)?;
+
+    Ok(Pubkey::from(fill.redeemer()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_execute_fill_selector() {
+        let hash = solana_program::hash::hash(b"global:execute_fill");
+        assert_eq!(EXECUTE_FILL_SELECTOR, hash.to_bytes()[..8]);
+    }
+}
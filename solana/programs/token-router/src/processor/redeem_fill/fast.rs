@@ -1,5 +1,6 @@
 use crate::{
     composite::*,
+    events::FillRedeemed,
     state::{Custodian, FillType, PreparedFill, PreparedFillInfo, PreparedFillSeeds},
 };
 use anchor_lang::prelude::*;
@@ -7,6 +8,7 @@ use anchor_spl::token;
 use matching_engine::state::FastFill;
 
 /// Accounts required for [redeem_fast_fill].
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RedeemFastFill<'info> {
     #[account(mut)]
@@ -127,6 +129,16 @@ fn handle_redeem_fast_fill(ctx: Context<RedeemFastFill>) -> Result<()> {
     let redeemer_message = std::mem::take(&mut ctx.accounts.fast_fill.redeemer_message);
     let fast_fill = &ctx.accounts.fast_fill;
 
+    let fill_redeemed = FillRedeemed {
+        prepared_fill: ctx.accounts.prepared_fill.key(),
+        fill_source: fast_fill.key(),
+        fill_type: FillType::FastFill,
+        source_chain: fast_fill.seeds.source_chain,
+        order_sender: fast_fill.seeds.order_sender,
+        redeemer: fast_fill.info.redeemer,
+        amount: fast_fill.info.amount,
+    };
+
     // Set prepared fill data.
     ctx.accounts.prepared_fill.set_inner(PreparedFill {
         seeds: PreparedFillSeeds {
@@ -145,6 +157,8 @@ fn handle_redeem_fast_fill(ctx: Context<RedeemFastFill>) -> Result<()> {
         redeemer_message,
     });
 
+    emit_cpi!(fill_redeemed);
+
     // Done.
     Ok(())
 }
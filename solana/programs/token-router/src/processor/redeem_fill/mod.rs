@@ -1,6 +1,9 @@
 mod cctp;
 pub use cctp::*;
 
+mod execute;
+pub use execute::*;
+
 mod fast;
 pub use fast::*;
 
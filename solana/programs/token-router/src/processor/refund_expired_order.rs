@@ -0,0 +1,122 @@
+use crate::{
+    composite::*,
+    error::TokenRouterError,
+    events::PreparedOrderClosed,
+    state::{Custodian, PreparedOrder},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+/// Accounts required for [refund_expired_order].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundExpiredOrder<'info> {
+    custodian: CheckedCustodian<'info>,
+
+    #[account(
+        mut,
+        close = prepared_by,
+        constraint = {
+            require!(
+                prepared_order.is_expired(Clock::get()?.slot),
+                TokenRouterError::OrderNotExpired
+            );
+
+            true
+        }
+    )]
+    prepared_order: Account<'info, PreparedOrder>,
+
+    /// CHECK: This payer must be the same one encoded in the prepared order.
+    #[account(
+        mut,
+        address = prepared_order.prepared_by,
+    )]
+    prepared_by: UncheckedAccount<'info>,
+
+    /// CHECK: This account must be the same one encoded in the prepared order.
+    #[account(
+        mut,
+        address = prepared_order.refund_token,
+    )]
+    refund_token: UncheckedAccount<'info>,
+
+    /// Custody token account. This account will be closed at the end of this instruction.
+    ///
+    /// CHECK: Mutable. Seeds must be \["prepared-custody", prepared_order.key()\].
+    #[account(
+        mut,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+        ],
+        bump = prepared_order.prepared_custody_token_bump,
+    )]
+    prepared_custody_token: Account<'info, token::TokenAccount>,
+
+    /// Token account where the keeper tip is paid.
+    #[account(
+        mut,
+        token::mint = common::USDC_MINT,
+    )]
+    keeper_token: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn refund_expired_order(ctx: Context<RefundExpiredOrder>) -> Result<()> {
+    let token_program = &ctx.accounts.token_program;
+    let custodian = &ctx.accounts.custodian;
+    let prepared_custody_token = &ctx.accounts.prepared_custody_token;
+
+    let keeper_tip = prepared_custody_token
+        .amount
+        .min(crate::REFUND_EXPIRED_ORDER_KEEPER_TIP);
+
+    // Pay the keeper who refunded this order.
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: prepared_custody_token.to_account_info(),
+                to: ctx.accounts.keeper_token.to_account_info(),
+                authority: custodian.to_account_info(),
+            },
+            &[Custodian::SIGNER_SEEDS],
+        ),
+        keeper_tip,
+    )?;
+
+    let amount = prepared_custody_token.amount.saturating_sub(keeper_tip);
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: prepared_custody_token.to_account_info(),
+                to: ctx.accounts.refund_token.to_account_info(),
+                authority: custodian.to_account_info(),
+            },
+            &[Custodian::SIGNER_SEEDS],
+        ),
+        amount,
+    )?;
+
+    emit_cpi!(PreparedOrderClosed {
+        prepared_order: ctx.accounts.prepared_order.key(),
+        order_sender: ctx.accounts.prepared_order.order_sender,
+        refund_token: ctx.accounts.refund_token.key(),
+        amount,
+        expired: true,
+    });
+
+    // Finally close token account.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: prepared_custody_token.to_account_info(),
+            destination: ctx.accounts.prepared_by.to_account_info(),
+            authority: custodian.to_account_info(),
+        },
+        &[Custodian::SIGNER_SEEDS],
+    ))
+}
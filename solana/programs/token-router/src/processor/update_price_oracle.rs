@@ -0,0 +1,29 @@
+use crate::state::PriceOracle;
+use anchor_lang::prelude::*;
+
+/// Accounts required for [update_price_oracle].
+#[derive(Accounts)]
+pub struct UpdatePriceOracle<'info> {
+    /// Publisher of the price feed.
+    authority: Signer<'info>,
+
+    /// The price oracle must have been registered by the owner or owner assistant.
+    #[account(
+        mut,
+        seeds = [
+            PriceOracle::SEED_PREFIX,
+            authority.key().as_ref(),
+        ],
+        bump = price_oracle.bump,
+    )]
+    price_oracle: Account<'info, PriceOracle>,
+}
+
+pub fn update_price_oracle(ctx: Context<UpdatePriceOracle>, price: u64) -> Result<()> {
+    let price_oracle = &mut ctx.accounts.price_oracle;
+    price_oracle.price = price;
+    price_oracle.publish_slot = Clock::get()?.slot;
+
+    // Done.
+    Ok(())
+}
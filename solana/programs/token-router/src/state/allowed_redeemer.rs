@@ -0,0 +1,48 @@
+use crate::error::TokenRouterError;
+use anchor_lang::prelude::*;
+use common::admin::utils::migrate::VersionedAccount;
+
+/// Redeemer program allowed to receive fills via [redeem_and_execute](crate::redeem_and_execute).
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct AllowedRedeemer {
+    pub bump: u8,
+
+    /// Redeemer program invoked with each fill.
+    pub program_id: Pubkey,
+
+    /// Token account controlled by the redeemer program, where fill tokens are transferred before
+    /// the redeemer program is invoked.
+    pub redeemer_token: Pubkey,
+
+    /// Schema version of this account. New fields must be added after this one.
+    pub version: u8,
+}
+
+impl AllowedRedeemer {
+    pub const SEED_PREFIX: &'static [u8] = b"allowed-redeemer";
+
+    /// Seed prefix of the PDA that signs for this program when invoking a redeemer program. Each
+    /// redeemer program gets its own authority, which has no authority over any token account.
+    pub const AUTHORITY_SEED_PREFIX: &'static [u8] = b"redeemer-authority";
+}
+
+impl VersionedAccount for AllowedRedeemer {
+    const VERSION: u8 = 1;
+    const SIZE: usize = 8 + Self::INIT_SPACE;
+    // Allowed redeemers were introduced with versioning.
+    const UNVERSIONED_SIZE: usize = 0;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+
+    fn upgrade_from(&mut self, _version: u8) -> Result<()> {
+        // There is no previous version to upgrade from.
+        err!(TokenRouterError::UnsupportedAccountVersion)
+    }
+}
@@ -1,4 +1,8 @@
+use crate::error::TokenRouterError;
 use anchor_lang::prelude::*;
+use common::admin::{
+    utils::migrate::VersionedAccount, OwnerAssistantRotation, OwnerSet, PendingOwnerWindow,
+};
 
 #[account]
 #[derive(Debug, InitSpace)]
@@ -16,6 +20,28 @@ pub struct Custodian {
     /// Indicate who last set the `paused` value. When the program is first initialized, this is set
     /// to the `owner`.
     pub paused_set_by: Pubkey,
+
+    /// M-of-N signers allowed to act as the owner. Inactive unless its threshold is nonzero.
+    /// Custodians created before this field was introduced are reallocated via `migrate`.
+    pub owner_set: OwnerSet,
+    pub pending_owner_set: Option<OwnerSet>,
+
+    /// Slot window in which the pending owner can confirm the ownership transfer request.
+    pub pending_owner_window: PendingOwnerWindow,
+
+    /// Previous owner assistant, which remains authorized until the rotation window expires.
+    pub owner_assistant_rotation: Option<OwnerAssistantRotation>,
+
+    /// Schema version of this account. New fields must be added after this one.
+    pub version: u8,
+
+    /// Maximum integrator fee (in bps) an order can pay. Introduced in version 2.
+    pub max_integrator_fee_bps: u16,
+
+    /// Token account where expired prepared fills from foreign chains are forwarded (see
+    /// [forward_expired_prepared_fill](crate::forward_expired_prepared_fill)). Introduced in version
+    /// 3.
+    pub fill_rescue_token: Option<Pubkey>,
 }
 
 impl Custodian {
@@ -24,6 +50,70 @@ impl Custodian {
     pub const SIGNER_SEEDS: &'static [&'static [u8]] = &[Self::SEED_PREFIX, &[Self::BUMP]];
 }
 
+impl VersionedAccount for Custodian {
+    const VERSION: u8 = 3;
+    const SIZE: usize = 8 + Self::INIT_SPACE;
+    const UNVERSIONED_SIZE: usize = 8 // discriminator
+        + 1 // paused
+        + 32 // owner
+        + 33 // pending_owner
+        + 32 // owner_assistant
+        + 32 // paused_set_by
+    ;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+
+    fn read_version(buf: &mut &[u8], unversioned: bool) -> Result<u8> {
+        <(bool, Pubkey, Option<Pubkey>, Pubkey, Pubkey)>::deserialize(buf)?;
+        if unversioned {
+            return Ok(0);
+        }
+
+        <(
+            OwnerSet,
+            Option<OwnerSet>,
+            PendingOwnerWindow,
+            Option<OwnerAssistantRotation>,
+        )>::deserialize(buf)?;
+        let version = u8::deserialize(buf)?;
+
+        // Fields introduced by each later version.
+        if version >= 2 {
+            u16::deserialize(buf)?;
+        }
+        if version >= 3 {
+            Option::<Pubkey>::deserialize(buf)?;
+        }
+
+        Ok(version)
+    }
+
+    fn upgrade_from(&mut self, version: u8) -> Result<()> {
+        match version {
+            // Accounts created before versioning have no other fields to set.
+            0 => Ok(()),
+            // Integrator fees are disabled until the owner sets a maximum.
+            1 => {
+                self.max_integrator_fee_bps = 0;
+                Ok(())
+            }
+            // Expired prepared fills from foreign chains cannot be forwarded until the owner sets
+            // a rescue token account.
+            2 => {
+                self.fill_rescue_token = None;
+                Ok(())
+            }
+            _ => err!(TokenRouterError::UnsupportedAccountVersion),
+        }
+    }
+}
+
 impl common::admin::Ownable for Custodian {
     fn owner(&self) -> &Pubkey {
         &self.owner
@@ -42,6 +132,32 @@ impl common::admin::PendingOwner for Custodian {
     fn pending_owner_mut(&mut self) -> &mut Option<Pubkey> {
         &mut self.pending_owner
     }
+
+    fn pending_owner_window(&self) -> &PendingOwnerWindow {
+        &self.pending_owner_window
+    }
+
+    fn pending_owner_window_mut(&mut self) -> &mut PendingOwnerWindow {
+        &mut self.pending_owner_window
+    }
+}
+
+impl common::admin::MultisigOwnable for Custodian {
+    fn owner_set(&self) -> &OwnerSet {
+        &self.owner_set
+    }
+
+    fn owner_set_mut(&mut self) -> &mut OwnerSet {
+        &mut self.owner_set
+    }
+
+    fn pending_owner_set(&self) -> &Option<OwnerSet> {
+        &self.pending_owner_set
+    }
+
+    fn pending_owner_set_mut(&mut self) -> &mut Option<OwnerSet> {
+        &mut self.pending_owner_set
+    }
 }
 
 impl common::admin::OwnerAssistant for Custodian {
@@ -52,14 +168,36 @@ impl common::admin::OwnerAssistant for Custodian {
     fn owner_assistant_mut(&mut self) -> &mut Pubkey {
         &mut self.owner_assistant
     }
+
+    fn owner_assistant_rotation(&self) -> &Option<OwnerAssistantRotation> {
+        &self.owner_assistant_rotation
+    }
+
+    fn owner_assistant_rotation_mut(&mut self) -> &mut Option<OwnerAssistantRotation> {
+        &mut self.owner_assistant_rotation
+    }
 }
 
 #[cfg(test)]
 mod test {
+    #![allow(clippy::arithmetic_side_effects)]
+
+    use anchor_lang::Discriminator;
+    use common::admin::utils::migrate::upgrade_account_data;
     use solana_program::pubkey::Pubkey;
 
     use super::*;
 
+    /// Custodian layout before owner sets were introduced.
+    #[derive(AnchorSerialize)]
+    struct LegacyCustodian {
+        paused: bool,
+        owner: Pubkey,
+        pending_owner: Option<Pubkey>,
+        owner_assistant: Pubkey,
+        paused_set_by: Pubkey,
+    }
+
     #[test]
     fn test_bump() {
         let (custodian, bump) =
@@ -71,4 +209,136 @@ mod test {
             "custodian mismatch",
         );
     }
+
+    #[test]
+    fn upgrade_legacy_custodian() {
+        let legacy = LegacyCustodian {
+            paused: true,
+            owner: Pubkey::new_from_array([1; 32]),
+            pending_owner: Some(Pubkey::new_from_array([2; 32])),
+            owner_assistant: Pubkey::new_from_array([3; 32]),
+            paused_set_by: Pubkey::new_from_array([4; 32]),
+        };
+
+        let mut data = Custodian::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        let unversioned = data.len() <= Custodian::UNVERSIONED_SIZE;
+        assert!(unversioned);
+
+        // Reallocating zero-initializes the new bytes.
+        data.resize(Custodian::SIZE, 0);
+        assert!(upgrade_account_data::<Custodian>(
+            &mut data,
+            unversioned,
+            error!(TokenRouterError::UnsupportedAccountVersion)
+        )
+        .unwrap());
+
+        let upgraded = Custodian::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(upgraded.version, Custodian::VERSION);
+        assert!(upgraded.paused);
+        assert_eq!(upgraded.owner, legacy.owner);
+        assert_eq!(upgraded.pending_owner, legacy.pending_owner);
+        assert_eq!(upgraded.owner_assistant, legacy.owner_assistant);
+        assert_eq!(upgraded.paused_set_by, legacy.paused_set_by);
+        assert_eq!(upgraded.owner_set, OwnerSet::default());
+        assert_eq!(upgraded.pending_owner_set, None);
+        assert_eq!(upgraded.pending_owner_window, PendingOwnerWindow::default());
+        assert_eq!(upgraded.owner_assistant_rotation, None);
+        assert_eq!(upgraded.max_integrator_fee_bps, 0);
+        assert_eq!(upgraded.fill_rescue_token, None);
+
+        // Upgrading an account at the current version does nothing.
+        assert!(!upgrade_account_data::<Custodian>(
+            &mut data,
+            false,
+            error!(TokenRouterError::UnsupportedAccountVersion)
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn upgrade_legacy_custodian_with_stale_bytes() {
+        let mut legacy = LegacyCustodian {
+            paused: false,
+            owner: Pubkey::new_from_array([1; 32]),
+            pending_owner: Some(Pubkey::new_from_array([2; 32])),
+            owner_assistant: Pubkey::new_from_array([3; 32]),
+            paused_set_by: Pubkey::new_from_array([4; 32]),
+        };
+
+        let mut data = Custodian::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+
+        // Cancelling the ownership transfer request shortens the serialization, leaving stale bytes
+        // where the fields introduced by later versions are read.
+        legacy.pending_owner = None;
+        let mut writer = &mut data[8..];
+        legacy.serialize(&mut writer).unwrap();
+        assert!(data[data.len() - 32..].iter().any(|&b| b != 0));
+
+        data.resize(Custodian::SIZE, 0);
+        assert!(upgrade_account_data::<Custodian>(
+            &mut data,
+            true,
+            error!(TokenRouterError::UnsupportedAccountVersion)
+        )
+        .unwrap());
+
+        let upgraded = Custodian::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(upgraded.version, Custodian::VERSION);
+        assert_eq!(upgraded.owner, legacy.owner);
+        assert_eq!(upgraded.pending_owner, None);
+        assert_eq!(upgraded.paused_set_by, legacy.paused_set_by);
+        assert_eq!(upgraded.owner_set, OwnerSet::default());
+        assert_eq!(upgraded.pending_owner_set, None);
+        assert_eq!(upgraded.pending_owner_window, PendingOwnerWindow::default());
+        assert_eq!(upgraded.owner_assistant_rotation, None);
+        assert_eq!(upgraded.max_integrator_fee_bps, 0);
+        assert_eq!(upgraded.fill_rescue_token, None);
+    }
+
+    #[test]
+    fn upgrade_version_one_custodian() {
+        let mut custodian = Custodian {
+            paused: false,
+            owner: Pubkey::new_from_array([1; 32]),
+            pending_owner: None,
+            owner_assistant: Pubkey::new_from_array([3; 32]),
+            paused_set_by: Pubkey::new_from_array([1; 32]),
+            owner_set: OwnerSet::default(),
+            pending_owner_set: None,
+            pending_owner_window: PendingOwnerWindow::default(),
+            owner_assistant_rotation: Some(OwnerAssistantRotation::new(
+                Pubkey::new_from_array([5; 32]),
+                69,
+            )),
+            version: 1,
+            max_integrator_fee_bps: 0,
+            fill_rescue_token: None,
+        };
+
+        // Version one did not write any fields after the version, so these bytes may be stale (like
+        // the bytes left over after the owner assistant rotation was cleared).
+        custodian.owner_assistant_rotation = None;
+        let mut written = Vec::new();
+        custodian.try_serialize(&mut written).unwrap();
+        let version_one_len = written.len() - 2 - 1;
+
+        let mut data = vec![0xff; Custodian::SIZE];
+        data[..version_one_len].copy_from_slice(&written[..version_one_len]);
+
+        assert!(upgrade_account_data::<Custodian>(
+            &mut data,
+            false,
+            error!(TokenRouterError::UnsupportedAccountVersion)
+        )
+        .unwrap());
+
+        let upgraded = Custodian::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(upgraded.version, Custodian::VERSION);
+        assert_eq!(upgraded.owner_assistant_rotation, None);
+        assert_eq!(upgraded.max_integrator_fee_bps, 0);
+        assert_eq!(upgraded.fill_rescue_token, None);
+    }
 }
@@ -0,0 +1,189 @@
+//! Snapshot tests pinning the serialized layout of every account. If one of these fails, an
+//! account schema changed in a way that breaks existing accounts. Either revert the change or add a
+//! migration (see [VersionedAccount](common::admin::utils::migrate::VersionedAccount)) and update
+//! the snapshot with the layout printed by the failing test.
+
+#![allow(clippy::panic)]
+
+use super::*;
+use anchor_lang::prelude::*;
+use common::{
+    admin::{utils::migrate::VersionedAccount, OwnerSet, PendingOwnerWindow, RoleMember},
+    layout::AccountLayout,
+};
+
+fn assert_layout<T: AccountSerialize>(account: &T, layout: AccountLayout, snapshot: &str) {
+    if let Err(err) = layout.check(account, snapshot) {
+        panic!("{err}");
+    }
+}
+
+fn key(n: u8) -> Pubkey {
+    Pubkey::new_from_array([n; 32])
+}
+
+#[test]
+fn allowed_redeemer() {
+    let account = AllowedRedeemer {
+        bump: 250,
+        program_id: key(1),
+        redeemer_token: key(2),
+        version: AllowedRedeemer::VERSION,
+    };
+    let layout = AccountLayout::new::<AllowedRedeemer>()
+        .field("bump", &account.bump)
+        .field("program_id", &account.program_id)
+        .field("redeemer_token", &account.redeemer_token)
+        .field("version", &account.version);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/allowed_redeemer.txt"),
+    );
+}
+
+#[test]
+fn custodian() {
+    let account = Custodian {
+        paused: true,
+        owner: key(1),
+        pending_owner: None,
+        owner_assistant: key(2),
+        paused_set_by: key(3),
+        owner_set: OwnerSet::new(1, &[key(4)]).unwrap(),
+        pending_owner_set: Some(OwnerSet::new(2, &[key(5), key(6)]).unwrap()),
+        pending_owner_window: PendingOwnerWindow {
+            requested_slot: 100,
+            expiry_slot: 200,
+        },
+        owner_assistant_rotation: None,
+        version: Custodian::VERSION,
+        max_integrator_fee_bps: 50,
+        fill_rescue_token: Some(key(7)),
+    };
+    let layout = AccountLayout::new::<Custodian>()
+        .field("paused", &account.paused)
+        .field("owner", &account.owner)
+        .field("pending_owner", &account.pending_owner)
+        .field("owner_assistant", &account.owner_assistant)
+        .field("paused_set_by", &account.paused_set_by)
+        .field("owner_set", &account.owner_set)
+        .field("pending_owner_set", &account.pending_owner_set)
+        .field("pending_owner_window", &account.pending_owner_window)
+        .field(
+            "owner_assistant_rotation",
+            &account.owner_assistant_rotation,
+        )
+        .field("version", &account.version)
+        .field("max_integrator_fee_bps", &account.max_integrator_fee_bps)
+        .field("fill_rescue_token", &account.fill_rescue_token);
+
+    assert_layout(&account, layout, include_str!("snapshots/custodian.txt"));
+}
+
+#[test]
+fn prepared_fill() {
+    let account = PreparedFill {
+        seeds: PreparedFillSeeds {
+            fill_source: key(1),
+            bump: 254,
+        },
+        info: PreparedFillInfo {
+            prepared_custody_token_bump: 253,
+            prepared_by: key(2),
+            fill_type: FillType::FastFill,
+            source_chain: 6,
+            order_sender: [3; 32],
+            redeemer: key(4),
+            timestamp: 1_700_000_000,
+        },
+        redeemer_message: b"hello".to_vec(),
+    };
+    let layout = AccountLayout::new::<PreparedFill>()
+        .field("seeds", &account.seeds)
+        .field("info", &account.info)
+        .field("redeemer_message", &account.redeemer_message);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/prepared_fill.txt"),
+    );
+}
+
+#[test]
+fn prepared_order() {
+    let account = PreparedOrder {
+        info: PreparedOrderInfo {
+            prepared_custody_token_bump: 252,
+            order_sender: key(1),
+            prepared_by: key(2),
+            order_type: OrderType::Market {
+                min_amount_out: Some(1_000),
+            },
+            src_token: key(3),
+            refund_token: key(4),
+            target_chain: 2,
+            redeemer: [5; 32],
+        },
+        redeemer_message: b"hello".to_vec(),
+        expiry_slot: Some(5_000),
+        relayer_fee: 2_500,
+        integrator_fee: Some(IntegratorFee {
+            token: key(6),
+            fee_bps: 25,
+        }),
+    };
+    let layout = AccountLayout::new::<PreparedOrder>()
+        .field("info", &account.info)
+        .field("redeemer_message", &account.redeemer_message)
+        .field("expiry_slot", &account.expiry_slot)
+        .field("relayer_fee", &account.relayer_fee)
+        .field("integrator_fee", &account.integrator_fee);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/prepared_order.txt"),
+    );
+}
+
+#[test]
+fn price_oracle() {
+    let account = PriceOracle {
+        bump: 251,
+        authority: key(1),
+        price: 42_000,
+        publish_slot: 100,
+    };
+    let layout = AccountLayout::new::<PriceOracle>()
+        .field("bump", &account.bump)
+        .field("authority", &account.authority)
+        .field("price", &account.price)
+        .field("publish_slot", &account.publish_slot);
+
+    assert_layout(&account, layout, include_str!("snapshots/price_oracle.txt"));
+}
+
+#[test]
+fn role_registry() {
+    let account = RoleRegistry {
+        bump: 246,
+        members: vec![RoleMember {
+            key: key(1),
+            roles: 3,
+        }],
+        version: RoleRegistry::VERSION,
+    };
+    let layout = AccountLayout::new::<RoleRegistry>()
+        .field("bump", &account.bump)
+        .field("members", &account.members)
+        .field("version", &account.version);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/role_registry.txt"),
+    );
+}
@@ -1,3 +1,6 @@
+mod allowed_redeemer;
+pub use allowed_redeemer::*;
+
 mod custodian;
 pub use custodian::*;
 
@@ -6,3 +9,12 @@ pub use prepared_fill::*;
 
 mod prepared_order;
 pub use prepared_order::*;
+
+mod price_oracle;
+pub use price_oracle::*;
+
+mod role_registry;
+pub use role_registry::*;
+
+#[cfg(test)]
+mod layout;
@@ -37,11 +37,14 @@ pub struct PreparedFillInfo {
     /// fill from another Token Router or timestamp from [matching_engine::state::FastFill] as a
     /// result of a market order.
     ///
-    /// NOTE: This timestamp is not used by the Token Router. It only provides more information for
-    /// an integrator so he can perform special handling based on when the fill happened.
+    /// The Token Router uses this timestamp to determine when an unconsumed fill can be forwarded
+    /// (see [forward_expired_prepared_fill](crate::forward_expired_prepared_fill)). It also lets an
+    /// integrator perform special handling based on when the fill happened.
     pub timestamp: i64,
 }
 
+/// NOTE: This account is not versioned (see [VersionedAccount](common::admin::utils::migrate::VersionedAccount)) because it is closed once the fill
+/// is consumed or forwarded.
 #[account]
 #[derive(Debug)]
 pub struct PreparedFill {
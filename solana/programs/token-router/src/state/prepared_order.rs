@@ -2,7 +2,100 @@ use anchor_lang::prelude::*;
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub enum OrderType {
-    Market { min_amount_out: Option<u64> },
+    Market {
+        min_amount_out: Option<u64>,
+    },
+    /// Market order auctioned by the Matching Engine, published as a `FastMarketOrder`.
+    FastMarket {
+        min_amount_out: Option<u64>,
+        parameters: FastOrderParameters,
+    },
+    /// Market order placed by a keeper once the price reported by an oracle meets the condition.
+    Limit {
+        min_amount_out: Option<u64>,
+        condition: PriceCondition,
+        keeper_tip: u64,
+    },
+    /// Market order placed by a keeper once the unlock slot is reached.
+    TimeLocked {
+        min_amount_out: Option<u64>,
+        unlock_slot: u64,
+        keeper_tip: u64,
+    },
+}
+
+/// Auction parameters of a fast market order.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct FastOrderParameters {
+    /// Maximum fee the auction winner can charge to fulfill the order.
+    pub max_fee: u64,
+
+    /// Fee paid to whoever starts the auction.
+    pub init_auction_fee: u64,
+
+    /// Unix timestamp after which an auction can no longer be started. Zero means no deadline.
+    pub deadline: u32,
+}
+
+impl OrderType {
+    pub fn min_amount_out(&self) -> Option<u64> {
+        match self {
+            Self::Market { min_amount_out }
+            | Self::FastMarket { min_amount_out, .. }
+            | Self::Limit { min_amount_out, .. }
+            | Self::TimeLocked { min_amount_out, .. } => *min_amount_out,
+        }
+    }
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PriceTrigger {
+    AtOrAbove,
+    AtOrBelow,
+}
+
+/// Price condition of a limit order.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct PriceCondition {
+    /// [PriceOracle](crate::state::PriceOracle) account whose price is checked.
+    pub oracle: Pubkey,
+
+    /// Limit price, in the oracle's units.
+    pub price: u64,
+
+    pub trigger: PriceTrigger,
+}
+
+impl PriceCondition {
+    pub fn is_met(&self, price: u64) -> bool {
+        match self.trigger {
+            PriceTrigger::AtOrAbove => price >= self.price,
+            PriceTrigger::AtOrBelow => price <= self.price,
+        }
+    }
+}
+
+/// Fee paid to the integrator (e.g. a front-end) who routed an order, skimmed when the order is
+/// placed.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct IntegratorFee {
+    /// Token account where the fee is paid.
+    pub token: Pubkey,
+
+    /// Fee in bps of the amount in. Capped by the custodian's `max_integrator_fee_bps`.
+    pub fee_bps: u16,
+}
+
+impl IntegratorFee {
+    pub fn amount(&self, amount_in: u64) -> u64 {
+        let amount = u128::from(amount_in)
+            .saturating_mul(self.fee_bps.into())
+            .checked_div(crate::MAX_BPS.into())
+            .unwrap_or_default();
+
+        // The fee cannot exceed the amount in because the fee bps are capped at 100%.
+        amount.try_into().unwrap_or(amount_in)
+    }
 }
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
@@ -20,11 +113,23 @@ pub struct PreparedOrderInfo {
     pub redeemer: [u8; 32],
 }
 
+/// NOTE: This account is not versioned (see [VersionedAccount](common::admin::utils::migrate::VersionedAccount)) because it is closed once the order
+/// is placed, closed or refunded.
 #[account]
 #[derive(Debug)]
 pub struct PreparedOrder {
     pub info: PreparedOrderInfo,
     pub redeemer_message: Vec<u8>,
+
+    /// If provided, slot after which this order can no longer be placed. Anyone can refund an
+    /// expired order (see [refund_expired_order](crate::refund_expired_order)).
+    pub expiry_slot: Option<u64>,
+
+    /// Fee paid to the relayer who places this order, which is deducted from the amount in.
+    pub relayer_fee: u64,
+
+    /// If provided, fee paid to the integrator who routed this order.
+    pub integrator_fee: Option<IntegratorFee>,
 }
 
 impl PreparedOrder {
@@ -32,10 +137,18 @@ impl PreparedOrder {
         const FIXED: usize = 8 // DISCRIMINATOR
             + PreparedOrderInfo::INIT_SPACE
             + 4 // redeemer_message_len
+            + 9 // expiry_slot
+            + 8 // relayer_fee
+            + 1 + IntegratorFee::INIT_SPACE // integrator_fee
         ;
 
         redeemer_message_len.saturating_add(FIXED)
     }
+
+    pub fn is_expired(&self, slot: u64) -> bool {
+        self.expiry_slot
+            .is_some_and(|expiry_slot| slot > expiry_slot)
+    }
 }
 
 impl std::ops::Deref for PreparedOrder {
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Price feed read by limit orders. Feeds are registered by the owner or owner assistant, and only
+/// the registered authority can publish prices to its feed.
+pub struct PriceOracle {
+    pub bump: u8,
+
+    /// Key allowed to publish prices to this feed.
+    pub authority: Pubkey,
+
+    /// Latest published price.
+    pub price: u64,
+
+    /// Slot at which the latest price was published.
+    pub publish_slot: u64,
+}
+
+impl PriceOracle {
+    pub const SEED_PREFIX: &'static [u8] = b"price-oracle";
+
+    /// Whether the latest price cannot be used to trigger an order at the given slot, either
+    /// because no price has been published yet or because it was published more than
+    /// [MAX_PRICE_ORACLE_AGE_SLOTS](crate::MAX_PRICE_ORACLE_AGE_SLOTS) ago.
+    pub fn is_stale(&self, slot: u64) -> bool {
+        self.publish_slot == 0
+            || slot > self.publish_slot.saturating_add(crate::MAX_PRICE_ORACLE_AGE_SLOTS)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::arithmetic_side_effects)]
+
+    use super::*;
+    use crate::MAX_PRICE_ORACLE_AGE_SLOTS;
+
+    #[test]
+    fn is_stale() {
+        let mut oracle = PriceOracle {
+            bump: 255,
+            authority: Pubkey::new_unique(),
+            price: 0,
+            publish_slot: 0,
+        };
+
+        // A registered oracle without a published price can never trigger an order.
+        assert!(oracle.is_stale(0));
+        assert!(oracle.is_stale(MAX_PRICE_ORACLE_AGE_SLOTS));
+
+        oracle.price = 42_000;
+        oracle.publish_slot = 1_000;
+        assert!(!oracle.is_stale(1_000));
+        assert!(!oracle.is_stale(1_000 + MAX_PRICE_ORACLE_AGE_SLOTS));
+        assert!(oracle.is_stale(1_000 + MAX_PRICE_ORACLE_AGE_SLOTS + 1));
+    }
+}
@@ -2,7 +2,7 @@ use std::ops::Deref;
 
 use crate::{
     error::UpgradeManagerError,
-    state::{UpgradeReceipt, UpgradeStatus},
+    state::{UpgradeProposal, UpgradeReceipt, UpgradeStatus},
 };
 use anchor_lang::prelude::*;
 use wormhole_solana_utils::cpi::bpf_loader_upgradeable::{self, BpfLoaderUpgradeable};
@@ -51,12 +51,47 @@ pub struct ExecuteUpgrade<'info> {
     )]
     pub receipt: Account<'info, UpgradeReceipt>,
 
+    /// Upgrade proposed by the owner. The buffer's hash must match the proposed hash and the
+    /// upgrade delay must have elapsed.
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            UpgradeProposal::SEED_PREFIX,
+            program.key().as_ref(),
+            admin.owner.key().as_ref(),
+        ],
+        bump = proposal.bump,
+        constraint = {
+            require!(
+                Clock::get()?.slot >= proposal.slot_executable,
+                UpgradeManagerError::UpgradeTooEarly,
+            );
+            require!(
+                proposal.buffer_hash == crate::utils::buffer_hash(&buffer)?,
+                UpgradeManagerError::BufferHashMismatch,
+            );
+
+            true
+        }
+    )]
+    pub proposal: Account<'info, UpgradeProposal>,
+
     /// Deployed implementation of liquidity layer.
     ///
     /// CHECK: This address must be the deployed implementation pubkey.
     #[account(mut)]
     pub buffer: UncheckedAccount<'info>,
 
+    /// Buffer holding the program bytes currently deployed, which can be used to roll back the
+    /// upgrade before it is committed. This account is required for a new upgrade. It is ignored
+    /// when upgrading again before committing because the receipt already has a rollback buffer.
+    ///
+    /// CHECK: Program bytes must equal the program data's program bytes (checked in the upgrade
+    /// handler).
+    #[account(mut)]
+    pub rollback_buffer: Option<UncheckedAccount<'info>>,
+
     /// CHECK: Must be BPF Loader Upgradeable's PDA of liquidity layer program's program data.
     #[account(
         mut,
@@ -66,7 +101,7 @@ pub struct ExecuteUpgrade<'info> {
     )]
     pub program_data: Account<'info, ProgramData>,
 
-    /// CHECK: Must be Token Router program . We cannot use the Program<'info, ..> definition here
+    /// CHECK: Must be the program being upgraded. We cannot use the Program<'info, ..> definition here
     /// because we cannot set this account to be mutable in that case.
     #[account(mut)]
     pub program: UncheckedAccount<'info>,
@@ -74,6 +109,13 @@ pub struct ExecuteUpgrade<'info> {
     pub bpf_loader_upgradeable_program: Program<'info, BpfLoaderUpgradeable>,
     pub system_program: Program<'info, System>,
 
+    /// Liquidity layer programs use this sysvar to check that enough members of their owner set
+    /// signed this transaction.
+    ///
+    /// CHECK: Must equal instructions sysvar ID.
+    #[account(address = solana_program::sysvar::instructions::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
     pub sysvars: RequiredSysvars<'info>,
 }
 
@@ -92,9 +134,9 @@ pub struct CommitUpgrade<'info> {
     )]
     pub admin: ProgramOwnerOnly<'info>,
 
-    /// CHECK: This account will receive lamports from upgrade receipt.
+    /// CHECK: This account will receive lamports from upgrade receipt and rollback buffer.
     #[account(mut)]
-    recipient: UncheckedAccount<'info>,
+    pub recipient: UncheckedAccount<'info>,
 
     #[account(
         mut,
@@ -106,13 +148,20 @@ pub struct CommitUpgrade<'info> {
         bump = receipt.bump,
         constraint = {
             match receipt.status {
-                UpgradeStatus::Uncommitted { buffer: _, slot } => {
+                UpgradeStatus::Uncommitted { slot, rollback_buffer: expected_rollback_buffer, .. } => {
                     require_eq!(
                         slot,
                         program_data.slot,
                         UpgradeManagerError::ProgramDataMismatch,
                     );
 
+                    // The upgrade authority owns the rollback buffers of every program, so only
+                    // the receipt's rollback buffer can be passed in.
+                    require!(
+                        rollback_buffer.as_ref().map(|acc| acc.key()) == expected_rollback_buffer,
+                        UpgradeManagerError::RollbackBufferMismatch,
+                    );
+
                     Ok(())
                 },
                 _ => err!(UpgradeManagerError::NotUpgraded),
@@ -121,7 +170,14 @@ pub struct CommitUpgrade<'info> {
             true
         }
     )]
-    receipt: Account<'info, UpgradeReceipt>,
+    pub receipt: Account<'info, UpgradeReceipt>,
+
+    /// Rollback buffer recorded in the receipt, which is closed when the upgrade is committed.
+    /// Required if and only if the receipt has a rollback buffer.
+    ///
+    /// CHECK: Must equal the receipt's rollback buffer.
+    #[account(mut)]
+    pub rollback_buffer: Option<UncheckedAccount<'info>>,
 
     /// CHECK: Must be executable.
     #[account(executable)]
@@ -133,6 +189,15 @@ pub struct CommitUpgrade<'info> {
         seeds::program = bpf_loader_upgradeable::id(),
     )]
     program_data: Account<'info, ProgramData>,
+
+    /// Liquidity layer programs use this sysvar to check that enough members of their owner set
+    /// signed this transaction.
+    ///
+    /// CHECK: Must equal instructions sysvar ID.
+    #[account(address = solana_program::sysvar::instructions::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub bpf_loader_upgradeable_program: Program<'info, BpfLoaderUpgradeable>,
 }
 
 #[derive(Accounts)]
@@ -3,4 +3,13 @@ pub enum UpgradeManagerError {
     NotUpgraded = 0x10,
     ProgramDataMismatch = 0x12,
     OwnerMismatch = 0x14,
+    ProgramNotManaged = 0x16,
+    InvalidCustodian = 0x18,
+    UpgradeTooEarly = 0x1a,
+    BufferHashMismatch = 0x1c,
+    InvalidBuffer = 0x1e,
+    MissingRollbackBuffer = 0x20,
+    RollbackBufferMismatch = 0x22,
+    NoRollbackBuffer = 0x24,
+    UnsupportedAccountVersion = 0x26,
 }
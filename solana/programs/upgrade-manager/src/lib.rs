@@ -26,6 +26,20 @@ cfg_if::cfg_if! {
     }
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(feature = "integration-test")] {
+        /// Number of slots an owner must wait after proposing an upgrade before executing it.
+        const UPGRADE_PROPOSAL_DELAY: u64 = 8;
+    } else if #[cfg(feature = "testnet")] {
+        /// Number of slots an owner must wait after proposing an upgrade before executing it.
+        const UPGRADE_PROPOSAL_DELAY: u64 = 25;
+    } else {
+        /// Number of slots an owner must wait after proposing an upgrade before executing it
+        /// (about one day).
+        const UPGRADE_PROPOSAL_DELAY: u64 = 216_000;
+    }
+}
+
 const UPGRADE_AUTHORITY_SEED_PREFIX: &[u8] = b"upgrade";
 const UPGRADE_AUTHORITY_SIGNER_SEEDS: &[&[u8]] =
     &[UPGRADE_AUTHORITY_SEED_PREFIX, &[UPGRADE_AUTHORITY_BUMP]];
@@ -34,6 +48,56 @@ const UPGRADE_AUTHORITY_SIGNER_SEEDS: &[&[u8]] =
 pub mod upgrade_manager {
     use super::*;
 
+    // Program Registry
+
+    /// Register a program whose upgrade authority is the Upgrade Manager, along with the accounts
+    /// and instruction selectors used to transfer ownership of that program. The custodian must be
+    /// a PDA of the registered program derived from `custodian_seeds`. Only the Upgrade Manager's
+    /// upgrade authority can register programs.
+    pub fn register_program(
+        ctx: Context<RegisterProgram>,
+        layout: state::OwnershipCheckLayout,
+        custodian_seeds: Vec<Vec<u8>>,
+    ) -> Result<()> {
+        processor::register_program(ctx, layout, custodian_seeds)
+    }
+
+    /// Remove a program registration. Only the Upgrade Manager's upgrade authority can deregister
+    /// programs.
+    pub fn deregister_program(ctx: Context<DeregisterProgram>) -> Result<()> {
+        processor::deregister_program(ctx)
+    }
+
+    // Upgrade Proposals
+
+    /// Propose an upgrade for a program whose upgrade authority is the Upgrade Manager by
+    /// recording the SHA-256 hash of the program ELF written to the buffer (excluding the buffer's
+    /// metadata and any bytes beyond the ELF's length). The upgrade can be executed by the
+    /// same owner after the upgrade delay has elapsed, and only with a buffer whose hash matches.
+    /// Proposing again replaces the existing proposal and restarts the delay.
+    pub fn propose_upgrade(ctx: Context<ProposeUpgrade>, buffer_hash: [u8; 32]) -> Result<()> {
+        processor::propose_upgrade(ctx, buffer_hash)
+    }
+
+    /// Restore the program bytes from before an uncommitted upgrade using the rollback buffer
+    /// recorded in the upgrade receipt. Only the owner who executed the upgrade can roll it back.
+    /// The upgrade must still be committed afterwards to return program ownership to the owner.
+    pub fn rollback_upgrade(ctx: Context<RollbackUpgrade>) -> Result<()> {
+        processor::rollback_upgrade(ctx)
+    }
+
+    // Registered Programs
+
+    pub fn execute_upgrade(ctx: Context<ExecuteRegisteredUpgrade>) -> Result<()> {
+        utils::execute_upgrade(ctx.accounts, &ctx.bumps.execute_upgrade)
+    }
+
+    pub fn commit_upgrade<'info>(
+        ctx: Context<'_, '_, '_, 'info, CommitRegisteredUpgrade<'info>>,
+    ) -> Result<()> {
+        processor::commit_registered_upgrade(ctx)
+    }
+
     // Matching Engine
 
     pub fn execute_matching_engine_upgrade(
@@ -42,7 +106,9 @@ pub mod upgrade_manager {
         utils::execute_upgrade(ctx.accounts, &ctx.bumps.execute_upgrade)
     }
 
-    pub fn commit_matching_engine_upgrade(ctx: Context<CommitMatchingEngineUpgrade>) -> Result<()> {
+    pub fn commit_matching_engine_upgrade<'info>(
+        ctx: Context<'_, '_, '_, 'info, CommitMatchingEngineUpgrade<'info>>,
+    ) -> Result<()> {
         processor::commit_matching_engine_upgrade(ctx)
     }
 
@@ -52,7 +118,9 @@ pub mod upgrade_manager {
         utils::execute_upgrade(ctx.accounts, &ctx.bumps.execute_upgrade)
     }
 
-    pub fn commit_token_router_upgrade(ctx: Context<CommitTokenRouterUpgrade>) -> Result<()> {
+    pub fn commit_token_router_upgrade<'info>(
+        ctx: Context<'_, '_, '_, 'info, CommitTokenRouterUpgrade<'info>>,
+    ) -> Result<()> {
         processor::commit_token_router_upgrade(ctx)
     }
 }
@@ -3,6 +3,10 @@ use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct CommitMatchingEngineUpgrade<'info> {
+    /// Pays for any additional rent required to reallocate the Matching Engine's custodian.
+    #[account(mut)]
+    payer: Signer<'info>,
+
     /// CHECK: Seeds must be \["emitter"\] (Matching Engine program).
     #[account(mut)]
     matching_engine_custodian: UncheckedAccount<'info>,
@@ -18,31 +22,56 @@ pub struct CommitMatchingEngineUpgrade<'info> {
         }
     )]
     commit_upgrade: CommitUpgrade<'info>,
+
+    system_program: Program<'info, System>,
 }
 
-pub fn commit_matching_engine_upgrade(ctx: Context<CommitMatchingEngineUpgrade>) -> Result<()> {
+pub fn commit_matching_engine_upgrade<'info>(
+    ctx: Context<'_, '_, '_, 'info, CommitMatchingEngineUpgrade<'info>>,
+) -> Result<()> {
     let custodian = &ctx.accounts.matching_engine_custodian;
-    let CommitUpgrade { admin, program, .. } = &ctx.accounts.commit_upgrade;
+    let CommitUpgrade {
+        admin,
+        program,
+        instructions_sysvar,
+        ..
+    } = &ctx.accounts.commit_upgrade;
+
+    // The previous program bytes are no longer needed.
+    crate::utils::close_rollback_buffer(&ctx.accounts.commit_upgrade)?;
 
     // NOTE: We do not want to pass in any remaining accounts to this instruction.
     matching_engine::cpi::migrate(CpiContext::new_with_signer(
         program.to_account_info(),
         matching_engine::cpi::accounts::Migrate {
-            admin: matching_engine::cpi::accounts::OwnerOnly {
-                owner: admin.upgrade_authority.to_account_info(),
-                custodian: matching_engine::cpi::accounts::CheckedCustodian {
-                    custodian: custodian.to_account_info(),
-                },
-            },
+            payer: ctx.accounts.payer.to_account_info(),
+            owner: admin.upgrade_authority.to_account_info(),
+            instructions_sysvar: Some(instructions_sysvar.to_account_info()),
+            custodian: custodian.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
         },
         &[UPGRADE_AUTHORITY_SIGNER_SEEDS],
     ))?;
 
+    // Make sure the upgraded program can read its accounts before returning ownership. Any other
+    // versioned accounts (which must already be migrated via `migrate_accounts`) are passed in as
+    // remaining accounts and are forwarded to the health check.
+    matching_engine::cpi::health_check(
+        CpiContext::new(
+            program.to_account_info(),
+            matching_engine::cpi::accounts::HealthCheck {
+                custodian: custodian.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+    )?;
+
     matching_engine::cpi::submit_ownership_transfer_request(CpiContext::new_with_signer(
         program.to_account_info(),
         matching_engine::cpi::accounts::SubmitOwnershipTransferRequest {
             admin: matching_engine::cpi::accounts::OwnerOnlyMut {
                 owner: admin.upgrade_authority.to_account_info(),
+                instructions_sysvar: Some(instructions_sysvar.to_account_info()),
                 custodian: custodian.to_account_info(),
             },
             new_owner: admin.owner.to_account_info(),
@@ -28,6 +28,7 @@ impl<'info> AuthorizeUpgrade<'info> for ExecuteMatchingEngineUpgrade<'info> {
     fn authorize_upgrade(&self) -> Result<()> {
         let admin = &self.execute_upgrade.admin;
         let program = &self.execute_upgrade.program;
+        let instructions_sysvar = &self.execute_upgrade.instructions_sysvar;
         let custodian = &self.matching_engine_custodian;
 
         matching_engine::cpi::submit_ownership_transfer_request(CpiContext::new(
@@ -35,6 +36,7 @@ impl<'info> AuthorizeUpgrade<'info> for ExecuteMatchingEngineUpgrade<'info> {
             matching_engine::cpi::accounts::SubmitOwnershipTransferRequest {
                 admin: matching_engine::cpi::accounts::OwnerOnlyMut {
                     owner: admin.owner.to_account_info(),
+                    instructions_sysvar: Some(instructions_sysvar.to_account_info()),
                     custodian: custodian.to_account_info(),
                 },
                 new_owner: admin.upgrade_authority.to_account_info(),
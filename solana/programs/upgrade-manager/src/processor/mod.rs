@@ -1,5 +1,17 @@
 mod matching_engine_upgrade;
 pub use matching_engine_upgrade::*;
 
+mod program_registry;
+pub use program_registry::*;
+
+mod propose_upgrade;
+pub use propose_upgrade::*;
+
+mod registered_upgrade;
+pub use registered_upgrade::*;
+
+mod rollback_upgrade;
+pub use rollback_upgrade::*;
+
 mod token_router_upgrade;
 pub use token_router_upgrade::*;
@@ -0,0 +1,40 @@
+use crate::{error::UpgradeManagerError, state::ProgramRegistration};
+use anchor_lang::prelude::*;
+use wormhole_solana_utils::cpi::bpf_loader_upgradeable;
+
+#[derive(Accounts)]
+pub struct DeregisterProgram<'info> {
+    /// Upgrade authority of the Upgrade Manager program.
+    owner: Signer<'info>,
+
+    /// We use the program data to make sure the owner is the Upgrade Manager's upgrade authority.
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::id(),
+        constraint = {
+            upgrade_manager_program_data.upgrade_authority_address == Some(owner.key())
+        } @ UpgradeManagerError::OwnerMismatch,
+    )]
+    upgrade_manager_program_data: Account<'info, ProgramData>,
+
+    /// CHECK: This account will receive lamports from the program registration.
+    #[account(mut)]
+    recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [
+            ProgramRegistration::SEED_PREFIX,
+            registration.program.as_ref(),
+        ],
+        bump = registration.bump,
+    )]
+    registration: Account<'info, ProgramRegistration>,
+}
+
+pub fn deregister_program(_ctx: Context<DeregisterProgram>) -> Result<()> {
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,5 @@
+mod deregister;
+pub use deregister::*;
+
+mod register;
+pub use register::*;
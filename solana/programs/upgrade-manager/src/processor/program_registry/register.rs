@@ -0,0 +1,104 @@
+use crate::{
+    error::UpgradeManagerError,
+    state::{OwnershipCheckLayout, ProgramRegistration},
+};
+use anchor_lang::prelude::*;
+use common::admin::utils::migrate::VersionedAccount;
+use wormhole_solana_utils::cpi::bpf_loader_upgradeable;
+
+#[derive(Accounts)]
+#[instruction(layout: OwnershipCheckLayout)]
+pub struct RegisterProgram<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Upgrade authority of the Upgrade Manager program.
+    owner: Signer<'info>,
+
+    /// We use the program data to make sure the owner is the Upgrade Manager's upgrade authority.
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::id(),
+        constraint = {
+            upgrade_manager_program_data.upgrade_authority_address == Some(owner.key())
+        } @ UpgradeManagerError::OwnerMismatch,
+    )]
+    upgrade_manager_program_data: Account<'info, ProgramData>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProgramRegistration::INIT_SPACE,
+        seeds = [
+            ProgramRegistration::SEED_PREFIX,
+            program.key().as_ref(),
+        ],
+        bump,
+    )]
+    registration: Account<'info, ProgramRegistration>,
+
+    /// CHECK: Must be executable.
+    #[account(executable)]
+    program: UncheckedAccount<'info>,
+
+    /// CHECK: Must equal the layout's custodian and be owned by the registered program. Its
+    /// derivation is checked using the custodian seeds.
+    #[account(
+        address = layout.custodian @ UpgradeManagerError::InvalidCustodian,
+        owner = program.key() @ UpgradeManagerError::InvalidCustodian,
+    )]
+    custodian: UncheckedAccount<'info>,
+
+    /// The registered program's upgrade authority must be the Upgrade Manager.
+    #[account(
+        seeds = [program.key().as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::id(),
+        constraint = {
+            program_data.upgrade_authority_address == Some(common::UPGRADE_MANAGER_AUTHORITY)
+        } @ UpgradeManagerError::ProgramNotManaged,
+    )]
+    program_data: Account<'info, ProgramData>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn register_program(
+    ctx: Context<RegisterProgram>,
+    layout: OwnershipCheckLayout,
+    custodian_seeds: Vec<Vec<u8>>,
+) -> Result<()> {
+    let program = ctx.accounts.program.key();
+
+    // The custodian must be a PDA of the registered program.
+    let custodian_seeds = custodian_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect::<Vec<_>>();
+    let (expected_custodian, _) = Pubkey::find_program_address(&custodian_seeds, &program);
+    require_keys_eq!(
+        layout.custodian,
+        expected_custodian,
+        UpgradeManagerError::InvalidCustodian
+    );
+
+    let (_, interim_owner_bump) = Pubkey::find_program_address(
+        &[
+            ProgramRegistration::INTERIM_OWNER_SEED_PREFIX,
+            program.as_ref(),
+        ],
+        &crate::ID,
+    );
+
+    ctx.accounts.registration.set_inner(ProgramRegistration {
+        bump: ctx.bumps.registration,
+        program,
+        layout,
+        interim_owner_bump,
+        version: ProgramRegistration::VERSION,
+    });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::{error::UpgradeManagerError, state::UpgradeProposal};
+use anchor_lang::prelude::*;
+use wormhole_solana_utils::cpi::bpf_loader_upgradeable;
+
+#[derive(Accounts)]
+pub struct ProposeUpgrade<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Owner of the program. Ownership is checked when the upgrade is executed.
+    owner: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + UpgradeProposal::INIT_SPACE,
+        seeds = [
+            UpgradeProposal::SEED_PREFIX,
+            program.key().as_ref(),
+            owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    proposal: Account<'info, UpgradeProposal>,
+
+    /// CHECK: Must be executable.
+    #[account(executable)]
+    program: UncheckedAccount<'info>,
+
+    /// The program's upgrade authority must be the Upgrade Manager.
+    #[account(
+        seeds = [program.key().as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::id(),
+        constraint = {
+            program_data.upgrade_authority_address == Some(common::UPGRADE_MANAGER_AUTHORITY)
+        } @ UpgradeManagerError::ProgramNotManaged,
+    )]
+    program_data: Account<'info, ProgramData>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn propose_upgrade(ctx: Context<ProposeUpgrade>, buffer_hash: [u8; 32]) -> Result<()> {
+    let slot_proposed = Clock::get()?.slot;
+
+    ctx.accounts.proposal.set_inner(UpgradeProposal {
+        bump: ctx.bumps.proposal,
+        owner: ctx.accounts.owner.key(),
+        program: ctx.accounts.program.key(),
+        buffer_hash,
+        slot_proposed,
+        slot_executable: slot_proposed.saturating_add(crate::UPGRADE_PROPOSAL_DELAY),
+    });
+
+    msg!("proposed buffer hash: {}", hex::encode(buffer_hash));
+
+    // Done.
+    Ok(())
+}
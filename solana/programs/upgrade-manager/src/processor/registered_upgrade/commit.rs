@@ -0,0 +1,127 @@
+use crate::{composite::*, state::ProgramRegistration};
+use anchor_lang::{
+    prelude::*,
+    solana_program::program::{invoke, invoke_signed},
+};
+
+#[derive(Accounts)]
+pub struct CommitRegisteredUpgrade<'info> {
+    /// Pays for any additional rent required to reallocate the registered program's custodian.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            ProgramRegistration::SEED_PREFIX,
+            commit_upgrade.program.key().as_ref(),
+        ],
+        bump = registration.bump,
+    )]
+    registration: Account<'info, ProgramRegistration>,
+
+    /// CHECK: Must equal the registered program's custodian.
+    #[account(
+        mut,
+        address = registration.layout.custodian,
+    )]
+    custodian: UncheckedAccount<'info>,
+
+    /// Owns the registered program's custodian until the upgrade is committed.
+    ///
+    /// CHECK: Seeds must be \["interim-owner", program.key()\].
+    #[account(
+        seeds = [
+            ProgramRegistration::INTERIM_OWNER_SEED_PREFIX,
+            commit_upgrade.program.key().as_ref(),
+        ],
+        bump = registration.interim_owner_bump,
+    )]
+    interim_owner: UncheckedAccount<'info>,
+
+    commit_upgrade: CommitUpgrade<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn commit_registered_upgrade<'info>(
+    ctx: Context<'_, '_, '_, 'info, CommitRegisteredUpgrade<'info>>,
+) -> Result<()> {
+    let registration = &ctx.accounts.registration;
+    let layout = &registration.layout;
+    let custodian = &ctx.accounts.custodian;
+    let interim_owner = &ctx.accounts.interim_owner;
+    let CommitUpgrade {
+        admin,
+        program,
+        instructions_sysvar,
+        ..
+    } = &ctx.accounts.commit_upgrade;
+
+    let program_key = program.key();
+    let interim_owner_signer_seeds: &[&[u8]] = &[
+        ProgramRegistration::INTERIM_OWNER_SEED_PREFIX,
+        program_key.as_ref(),
+        &[registration.interim_owner_bump],
+    ];
+
+    // The previous program bytes are no longer needed.
+    crate::utils::close_rollback_buffer(&ctx.accounts.commit_upgrade)?;
+
+    // NOTE: We do not want to pass in any remaining accounts to this instruction.
+    if let Some(ix) = layout.migrate_ix(
+        &program.key(),
+        &ctx.accounts.payer.key(),
+        &interim_owner.key(),
+        &instructions_sysvar.key(),
+    ) {
+        invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.payer.to_account_info(),
+                interim_owner.to_account_info(),
+                instructions_sysvar.to_account_info(),
+                custodian.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                program.to_account_info(),
+            ],
+            &[interim_owner_signer_seeds],
+        )?;
+    }
+
+    // Make sure the upgraded program can read its accounts before returning ownership. Any other
+    // accounts the health check verifies are passed in as remaining accounts and are forwarded.
+    if let Some(ix) = layout.health_check_ix(&program.key(), ctx.remaining_accounts) {
+        let mut acc_infos = Vec::with_capacity(ctx.remaining_accounts.len().saturating_add(2));
+        acc_infos.push(custodian.to_account_info());
+        acc_infos.extend_from_slice(ctx.remaining_accounts);
+        acc_infos.push(program.to_account_info());
+        invoke(&ix, &acc_infos)?;
+    }
+
+    invoke_signed(
+        &layout.submit_ownership_transfer_ix(
+            &program.key(),
+            &interim_owner.key(),
+            &instructions_sysvar.key(),
+            &admin.owner.key(),
+        ),
+        &[
+            interim_owner.to_account_info(),
+            instructions_sysvar.to_account_info(),
+            custodian.to_account_info(),
+            admin.owner.to_account_info(),
+            program.to_account_info(),
+        ],
+        &[interim_owner_signer_seeds],
+    )?;
+
+    invoke(
+        &layout.confirm_ownership_transfer_ix(&program.key(), &admin.owner.key()),
+        &[
+            admin.owner.to_account_info(),
+            custodian.to_account_info(),
+            program.to_account_info(),
+        ],
+    )
+    .map_err(Into::into)
+}
@@ -0,0 +1,84 @@
+use crate::{composite::*, state::ProgramRegistration, utils::AuthorizeUpgrade};
+use anchor_lang::{
+    prelude::*,
+    solana_program::program::{invoke, invoke_signed},
+};
+
+#[derive(Accounts)]
+pub struct ExecuteRegisteredUpgrade<'info> {
+    #[account(
+        seeds = [
+            ProgramRegistration::SEED_PREFIX,
+            execute_upgrade.program.key().as_ref(),
+        ],
+        bump = registration.bump,
+    )]
+    registration: Account<'info, ProgramRegistration>,
+
+    /// CHECK: Must equal the registered program's custodian.
+    #[account(
+        mut,
+        address = registration.layout.custodian,
+    )]
+    custodian: UncheckedAccount<'info>,
+
+    /// Owns the registered program's custodian until the upgrade is committed.
+    ///
+    /// CHECK: Seeds must be \["interim-owner", program.key()\].
+    #[account(
+        seeds = [
+            ProgramRegistration::INTERIM_OWNER_SEED_PREFIX,
+            execute_upgrade.program.key().as_ref(),
+        ],
+        bump = registration.interim_owner_bump,
+    )]
+    interim_owner: UncheckedAccount<'info>,
+
+    execute_upgrade: ExecuteUpgrade<'info>,
+}
+
+impl<'info> AuthorizeUpgrade<'info> for ExecuteRegisteredUpgrade<'info> {
+    fn execute_upgrade_composite_mut(&mut self) -> &mut ExecuteUpgrade<'info> {
+        &mut self.execute_upgrade
+    }
+
+    fn authorize_upgrade(&self) -> Result<()> {
+        let layout = &self.registration.layout;
+        let admin = &self.execute_upgrade.admin;
+        let program = &self.execute_upgrade.program;
+        let instructions_sysvar = &self.execute_upgrade.instructions_sysvar;
+        let custodian = &self.custodian;
+        let interim_owner = &self.interim_owner;
+
+        invoke(
+            &layout.submit_ownership_transfer_ix(
+                &program.key(),
+                &admin.owner.key(),
+                &instructions_sysvar.key(),
+                &interim_owner.key(),
+            ),
+            &[
+                admin.owner.to_account_info(),
+                instructions_sysvar.to_account_info(),
+                custodian.to_account_info(),
+                interim_owner.to_account_info(),
+                program.to_account_info(),
+            ],
+        )?;
+
+        invoke_signed(
+            &layout.confirm_ownership_transfer_ix(&program.key(), &interim_owner.key()),
+            &[
+                interim_owner.to_account_info(),
+                custodian.to_account_info(),
+                program.to_account_info(),
+            ],
+            &[&[
+                ProgramRegistration::INTERIM_OWNER_SEED_PREFIX,
+                program.key().as_ref(),
+                &[self.registration.interim_owner_bump],
+            ]],
+        )
+        .map_err(Into::into)
+    }
+}
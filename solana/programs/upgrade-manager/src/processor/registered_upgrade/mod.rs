@@ -0,0 +1,5 @@
+mod execute;
+pub use execute::*;
+
+mod commit;
+pub use commit::*;
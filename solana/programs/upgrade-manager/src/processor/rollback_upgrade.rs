@@ -0,0 +1,116 @@
+use crate::{
+    composite::*,
+    error::UpgradeManagerError,
+    state::{UpgradeReceipt, UpgradeStatus},
+};
+use anchor_lang::prelude::*;
+use wormhole_solana_utils::cpi::bpf_loader_upgradeable::{self, BpfLoaderUpgradeable};
+
+#[derive(Accounts)]
+pub struct RollbackUpgrade<'info> {
+    /// Receives the lamports from the rollback buffer after the program is restored.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        constraint = {
+            require_keys_eq!(
+                admin.owner.key(),
+                receipt.owner,
+                UpgradeManagerError::OwnerMismatch,
+            );
+
+            true
+        }
+    )]
+    admin: ProgramOwnerOnly<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            UpgradeReceipt::SEED_PREFIX,
+            program.key().as_ref(),
+        ],
+        bump = receipt.bump,
+        constraint = {
+            match receipt.status {
+                UpgradeStatus::Uncommitted { rollback_buffer: Some(expected_rollback_buffer), .. } => {
+                    require_keys_eq!(
+                        rollback_buffer.key(),
+                        expected_rollback_buffer,
+                        UpgradeManagerError::RollbackBufferMismatch,
+                    );
+
+                    Ok(())
+                },
+                UpgradeStatus::Uncommitted { rollback_buffer: None, .. } => {
+                    err!(UpgradeManagerError::NoRollbackBuffer)
+                },
+                _ => err!(UpgradeManagerError::NotUpgraded),
+            }?;
+
+            true
+        }
+    )]
+    receipt: Account<'info, UpgradeReceipt>,
+
+    /// CHECK: Must equal the receipt's rollback buffer.
+    #[account(mut)]
+    rollback_buffer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [program.key().as_ref()],
+        bump = receipt.program_data_bump,
+        seeds::program = bpf_loader_upgradeable_program,
+    )]
+    program_data: Account<'info, ProgramData>,
+
+    /// CHECK: Must be the program being upgraded. We cannot use the Program<'info, ..> definition
+    /// here because we cannot set this account to be mutable in that case.
+    #[account(mut)]
+    program: UncheckedAccount<'info>,
+
+    bpf_loader_upgradeable_program: Program<'info, BpfLoaderUpgradeable>,
+
+    sysvars: RequiredSysvars<'info>,
+}
+
+pub fn rollback_upgrade(ctx: Context<RollbackUpgrade>) -> Result<()> {
+    let RollbackUpgrade {
+        payer,
+        admin,
+        receipt,
+        rollback_buffer,
+        program_data,
+        program,
+        bpf_loader_upgradeable_program,
+        sysvars,
+    } = ctx.accounts;
+
+    // The rollback buffer is consumed by the upgrade, so we record its hash beforehand.
+    let buffer_hash = crate::utils::buffer_hash(rollback_buffer)?;
+
+    // The program still needs to be committed to return ownership to the owner. There is nothing
+    // left to roll back to after this.
+    receipt.status = UpgradeStatus::Uncommitted {
+        buffer: rollback_buffer.key(),
+        buffer_hash,
+        rollback_buffer: None,
+        slot: Clock::get().unwrap().slot,
+    };
+
+    bpf_loader_upgradeable::upgrade(CpiContext::new_with_signer(
+        bpf_loader_upgradeable_program.to_account_info(),
+        bpf_loader_upgradeable::Upgrade {
+            program: program.to_account_info(),
+            program_data: program_data.to_account_info(),
+            buffer: rollback_buffer.to_account_info(),
+            authority: admin.upgrade_authority.to_account_info(),
+            spill: payer.to_account_info(),
+            rent: sysvars.rent.to_account_info(),
+            clock: sysvars.clock.to_account_info(),
+        },
+        &[crate::UPGRADE_AUTHORITY_SIGNER_SEEDS],
+    ))
+}
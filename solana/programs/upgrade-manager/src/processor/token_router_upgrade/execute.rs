@@ -28,6 +28,7 @@ impl<'info> AuthorizeUpgrade<'info> for ExecuteTokenRouterUpgrade<'info> {
     fn authorize_upgrade(&self) -> Result<()> {
         let admin = &self.execute_upgrade.admin;
         let program = &self.execute_upgrade.program;
+        let instructions_sysvar = &self.execute_upgrade.instructions_sysvar;
         let custodian = &self.token_router_custodian;
 
         token_router::cpi::submit_ownership_transfer_request(CpiContext::new(
@@ -35,6 +36,7 @@ impl<'info> AuthorizeUpgrade<'info> for ExecuteTokenRouterUpgrade<'info> {
             token_router::cpi::accounts::SubmitOwnershipTransferRequest {
                 admin: token_router::cpi::accounts::OwnerOnlyMut {
                     owner: admin.owner.to_account_info(),
+                    instructions_sysvar: Some(instructions_sysvar.to_account_info()),
                     custodian: custodian.to_account_info(),
                 },
                 new_owner: admin.upgrade_authority.to_account_info(),
@@ -0,0 +1,94 @@
+//! Snapshot tests pinning the serialized layout of every account. If one of these fails, an
+//! account schema changed in a way that breaks existing accounts. Either revert the change or
+//! migrate the affected accounts, and update the snapshot with the layout printed by the failing
+//! test.
+
+#![allow(clippy::panic)]
+
+use super::*;
+use anchor_lang::prelude::*;
+use common::{admin::utils::migrate::VersionedAccount, layout::AccountLayout};
+
+fn assert_layout<T: AccountSerialize>(account: &T, layout: AccountLayout, snapshot: &str) {
+    if let Err(err) = layout.check(account, snapshot) {
+        panic!("{err}");
+    }
+}
+
+fn key(n: u8) -> Pubkey {
+    Pubkey::new_from_array([n; 32])
+}
+
+#[test]
+fn upgrade_receipt() {
+    let account = UpgradeReceipt {
+        bump: 254,
+        program_data_bump: 253,
+        owner: key(1),
+        status: UpgradeStatus::Uncommitted {
+            buffer: key(2),
+            buffer_hash: [3; 32],
+            rollback_buffer: Some(key(4)),
+            slot: 100,
+        },
+    };
+    let layout = AccountLayout::new::<UpgradeReceipt>()
+        .field("bump", &account.bump)
+        .field("program_data_bump", &account.program_data_bump)
+        .field("owner", &account.owner)
+        .field("status", &account.status);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/upgrade_receipt.txt"),
+    );
+}
+
+#[test]
+fn upgrade_proposal() {
+    let account = UpgradeProposal {
+        bump: 252,
+        owner: key(1),
+        program: key(2),
+        buffer_hash: [3; 32],
+        slot_proposed: 100,
+        slot_executable: 108,
+    };
+    let layout = AccountLayout::new::<UpgradeProposal>()
+        .field("bump", &account.bump)
+        .field("owner", &account.owner)
+        .field("program", &account.program)
+        .field("buffer_hash", &account.buffer_hash)
+        .field("slot_proposed", &account.slot_proposed)
+        .field("slot_executable", &account.slot_executable);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/upgrade_proposal.txt"),
+    );
+}
+
+#[test]
+fn program_registration() {
+    let account = ProgramRegistration {
+        bump: 251,
+        program: key(1),
+        layout: OwnershipCheckLayout::anchor_default(key(2)),
+        interim_owner_bump: 254,
+        version: ProgramRegistration::VERSION,
+    };
+    let layout = AccountLayout::new::<ProgramRegistration>()
+        .field("bump", &account.bump)
+        .field("program", &account.program)
+        .field("layout", &account.layout)
+        .field("interim_owner_bump", &account.interim_owner_bump)
+        .field("version", &account.version);
+
+    assert_layout(
+        &account,
+        layout,
+        include_str!("snapshots/program_registration.txt"),
+    );
+}
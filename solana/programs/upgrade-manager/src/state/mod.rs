@@ -1,2 +1,11 @@
 mod upgrade_receipt;
 pub use upgrade_receipt::*;
+
+mod program_registration;
+pub use program_registration::*;
+
+mod upgrade_proposal;
+pub use upgrade_proposal::*;
+
+#[cfg(test)]
+mod layout;
@@ -0,0 +1,243 @@
+use crate::error::UpgradeManagerError;
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction};
+use common::admin::utils::migrate::VersionedAccount;
+
+/// Instruction selectors and accounts the Upgrade Manager needs to transfer ownership of a
+/// registered program to and from its upgrade authority.
+///
+/// The registered program is expected to follow the admin account layout found in the common
+/// admin module:
+/// * Submit ownership transfer: `[owner, instructions_sysvar?, custodian, new_owner]`.
+/// * Confirm ownership transfer: `[pending_owner, custodian]`.
+/// * Migrate: `[payer, owner, instructions_sysvar?, custodian, system_program]`.
+/// * Health check: `[custodian]`.
+///
+/// None of these instructions take any instruction arguments, so their instruction data is just
+/// the selector.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Eq, Copy)]
+pub struct OwnershipCheckLayout {
+    /// Account that stores the registered program's owner.
+    pub custodian: Pubkey,
+
+    pub submit_ownership_transfer_selector: [u8; 8],
+    pub confirm_ownership_transfer_selector: [u8; 8],
+
+    /// Migrate instruction invoked when an upgrade is committed. If none, the custodian is not
+    /// migrated.
+    pub migrate_selector: Option<[u8; 8]>,
+
+    /// Health check instruction invoked when an upgrade is committed (after migrating), which
+    /// should fail if the upgraded program cannot read its accounts. If none, no health check is
+    /// performed.
+    pub health_check_selector: Option<[u8; 8]>,
+
+    /// Whether the owner-only admin composite and migrate instruction have the optional
+    /// instructions sysvar account (used to check owner set approvals).
+    pub uses_instructions_sysvar: bool,
+}
+
+impl OwnershipCheckLayout {
+    /// Layout for programs built with Anchor using the common admin instruction names.
+    pub fn anchor_default(custodian: Pubkey) -> Self {
+        Self {
+            custodian,
+            submit_ownership_transfer_selector: anchor_selector(
+                "submit_ownership_transfer_request",
+            ),
+            confirm_ownership_transfer_selector: anchor_selector(
+                "confirm_ownership_transfer_request",
+            ),
+            migrate_selector: Some(anchor_selector("migrate")),
+            health_check_selector: Some(anchor_selector("health_check")),
+            uses_instructions_sysvar: true,
+        }
+    }
+
+    pub fn submit_ownership_transfer_ix(
+        &self,
+        program: &Pubkey,
+        owner: &Pubkey,
+        instructions_sysvar: &Pubkey,
+        new_owner: &Pubkey,
+    ) -> Instruction {
+        let mut accounts = Vec::with_capacity(4);
+        accounts.push(AccountMeta::new_readonly(*owner, true));
+        if self.uses_instructions_sysvar {
+            accounts.push(AccountMeta::new_readonly(*instructions_sysvar, false));
+        }
+        accounts.push(AccountMeta::new(self.custodian, false));
+        accounts.push(AccountMeta::new_readonly(*new_owner, false));
+
+        Instruction {
+            program_id: *program,
+            accounts,
+            data: self.submit_ownership_transfer_selector.to_vec(),
+        }
+    }
+
+    pub fn confirm_ownership_transfer_ix(
+        &self,
+        program: &Pubkey,
+        pending_owner: &Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: *program,
+            accounts: vec![
+                AccountMeta::new_readonly(*pending_owner, true),
+                AccountMeta::new(self.custodian, false),
+            ],
+            data: self.confirm_ownership_transfer_selector.to_vec(),
+        }
+    }
+
+    pub fn migrate_ix(
+        &self,
+        program: &Pubkey,
+        payer: &Pubkey,
+        owner: &Pubkey,
+        instructions_sysvar: &Pubkey,
+    ) -> Option<Instruction> {
+        self.migrate_selector.map(|selector| {
+            let mut accounts = Vec::with_capacity(5);
+            accounts.push(AccountMeta::new(*payer, true));
+            accounts.push(AccountMeta::new_readonly(*owner, true));
+            if self.uses_instructions_sysvar {
+                accounts.push(AccountMeta::new_readonly(*instructions_sysvar, false));
+            }
+            accounts.push(AccountMeta::new(self.custodian, false));
+            accounts.push(AccountMeta::new_readonly(
+                anchor_lang::system_program::ID,
+                false,
+            ));
+
+            Instruction {
+                program_id: *program,
+                accounts,
+                data: selector.to_vec(),
+            }
+        })
+    }
+
+    /// Health check instruction for the custodian followed by `remaining_accounts` (read-only).
+    pub fn health_check_ix(
+        &self,
+        program: &Pubkey,
+        remaining_accounts: &[AccountInfo],
+    ) -> Option<Instruction> {
+        self.health_check_selector.map(|selector| Instruction {
+            program_id: *program,
+            accounts: std::iter::once(AccountMeta::new_readonly(self.custodian, false))
+                .chain(
+                    remaining_accounts
+                        .iter()
+                        .map(|acc_info| AccountMeta::new_readonly(acc_info.key(), false)),
+                )
+                .collect(),
+            data: selector.to_vec(),
+        })
+    }
+}
+
+/// Registered program whose upgrade authority is the Upgrade Manager. Upgrades for this program
+/// can be executed and committed using the generic upgrade instructions.
+///
+/// While an upgrade is uncommitted, the registered program's custodian is owned by this program's
+/// interim owner (see [ProgramRegistration::INTERIM_OWNER_SEED_PREFIX]) instead of the upgrade
+/// authority. So the registered program never receives the upgrade authority's signature.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct ProgramRegistration {
+    pub bump: u8,
+    pub program: Pubkey,
+    pub layout: OwnershipCheckLayout,
+
+    /// Bump of the PDA that owns the registered program's custodian during an upgrade.
+    pub interim_owner_bump: u8,
+
+    /// Schema version of this account. New fields must be added after this one.
+    pub version: u8,
+}
+
+impl ProgramRegistration {
+    pub const SEED_PREFIX: &'static [u8] = b"registration";
+
+    /// Seed prefix of the PDA (one per registered program) that owns the registered program's
+    /// custodian during an upgrade. This PDA has no authority over any program.
+    pub const INTERIM_OWNER_SEED_PREFIX: &'static [u8] = b"interim-owner";
+}
+
+impl VersionedAccount for ProgramRegistration {
+    const VERSION: u8 = 1;
+    const SIZE: usize = 8 + Self::INIT_SPACE;
+    // Program registrations were introduced with versioning.
+    const UNVERSIONED_SIZE: usize = 0;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn version_mut(&mut self) -> &mut u8 {
+        &mut self.version
+    }
+
+    fn upgrade_from(&mut self, _version: u8) -> Result<()> {
+        // There is no previous version to upgrade from.
+        err!(UpgradeManagerError::UnsupportedAccountVersion)
+    }
+}
+
+fn anchor_selector(ix_name: &str) -> [u8; 8] {
+    let preimage = format!("global:{ix_name}");
+
+    let mut selector = [0; 8];
+    selector.copy_from_slice(
+        &anchor_lang::solana_program::hash::hash(preimage.as_bytes()).to_bytes()[..8],
+    );
+    selector
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn anchor_default_selectors() {
+        let layout = OwnershipCheckLayout::anchor_default(Pubkey::new_unique());
+
+        assert_eq!(
+            layout.submit_ownership_transfer_selector,
+            matching_engine::instruction::SubmitOwnershipTransferRequest::DISCRIMINATOR
+        );
+        assert_eq!(
+            layout.confirm_ownership_transfer_selector,
+            matching_engine::instruction::ConfirmOwnershipTransferRequest::DISCRIMINATOR
+        );
+        assert_eq!(
+            layout.migrate_selector,
+            Some(matching_engine::instruction::Migrate::DISCRIMINATOR)
+        );
+        assert_eq!(
+            layout.health_check_selector,
+            Some(matching_engine::instruction::HealthCheck::DISCRIMINATOR)
+        );
+    }
+
+    #[test]
+    fn submit_ownership_transfer_accounts() {
+        let program = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let new_owner = Pubkey::new_unique();
+        let sysvar = anchor_lang::solana_program::sysvar::instructions::id();
+
+        let mut layout = OwnershipCheckLayout::anchor_default(Pubkey::new_unique());
+        let ix = layout.submit_ownership_transfer_ix(&program, &owner, &sysvar, &new_owner);
+        assert_eq!(ix.accounts.len(), 4);
+        assert_eq!(ix.accounts[1].pubkey, sysvar);
+
+        layout.uses_instructions_sysvar = false;
+        let ix = layout.submit_ownership_transfer_ix(&program, &owner, &sysvar, &new_owner);
+        assert_eq!(ix.accounts.len(), 3);
+        assert_eq!(ix.accounts[1].pubkey, layout.custodian);
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// An upgrade proposed by a program's owner. An upgrade can only be executed with a buffer whose
+/// program ELF hashes to `buffer_hash`, and only at or after `slot_executable`.
+///
+/// NOTE: This account is not versioned (see
+/// [VersionedAccount](common::admin::utils::migrate::VersionedAccount)) because it is closed when
+/// the upgrade is executed.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct UpgradeProposal {
+    pub bump: u8,
+
+    pub owner: Pubkey,
+    pub program: Pubkey,
+
+    /// SHA-256 hash of the buffer's program bytes (excluding the buffer metadata).
+    pub buffer_hash: [u8; 32],
+
+    pub slot_proposed: u64,
+    pub slot_executable: u64,
+}
+
+impl UpgradeProposal {
+    pub const SEED_PREFIX: &'static [u8] = b"proposal";
+}
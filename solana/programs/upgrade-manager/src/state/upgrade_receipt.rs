@@ -5,16 +5,34 @@ use anchor_lang::prelude::*;
 pub enum UpgradeStatus {
     /// No status set.
     None,
-    /// An upgrade has been executed, but not committed.
-    Uncommitted { buffer: Pubkey, slot: u64 },
+    /// An upgrade has been executed, but not committed. The rollback buffer holds the program
+    /// bytes from before the upgrade, and is none once the upgrade has been rolled back.
+    Uncommitted {
+        buffer: Pubkey,
+        buffer_hash: [u8; 32],
+        rollback_buffer: Option<Pubkey>,
+        slot: u64,
+    },
 }
 
 impl std::fmt::Display for UpgradeStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             UpgradeStatus::None => write!(f, "None"),
-            UpgradeStatus::Uncommitted { buffer, slot } => {
-                write!(f, "Uncommitted {{ buffer: {}, slot: {} }}", buffer, slot)
+            UpgradeStatus::Uncommitted {
+                buffer,
+                buffer_hash,
+                rollback_buffer,
+                slot,
+            } => {
+                write!(
+                    f,
+                    "Uncommitted {{ buffer: {}, buffer_hash: {}, rollback_buffer: {:?}, slot: {} }}",
+                    buffer,
+                    hex::encode(buffer_hash),
+                    rollback_buffer,
+                    slot
+                )
             }
         }
     }
@@ -25,7 +43,9 @@ impl std::fmt::Display for UpgradeStatus {
 ///
 /// NOTE: Please be careful with modifying the schema of this account. If you upgrade a program
 /// without committing, and follow it with an Upgrade Manager program upgrade with a new receipt
-/// serialization, you will have a bad time.
+/// serialization, you will have a bad time. This account is not versioned (see
+/// [VersionedAccount](common::admin::utils::migrate::VersionedAccount)) because it is closed when
+/// the upgrade is committed.
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct UpgradeReceipt {
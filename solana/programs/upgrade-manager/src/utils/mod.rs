@@ -3,7 +3,15 @@ use crate::{
     error::UpgradeManagerError,
     state::{UpgradeReceipt, UpgradeStatus},
 };
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        bpf_loader_upgradeable::{self as loader, UpgradeableLoaderState},
+        hash,
+        program::invoke_signed,
+        program_memory::sol_memcmp,
+    },
+};
 use wormhole_solana_utils::cpi::bpf_loader_upgradeable;
 
 pub trait AuthorizeUpgrade<'info> {
@@ -41,7 +49,7 @@ fn handle_upgrade_with_status<'info, A>(accounts: &mut A, bumps: &ExecuteUpgrade
 where
     A: AuthorizeUpgrade<'info>,
 {
-    let status = {
+    {
         let execute_upgrade = execute_upgrade_composite(accounts);
 
         // Only the owner can upgrade again.
@@ -50,11 +58,8 @@ where
             execute_upgrade.receipt.owner,
             UpgradeManagerError::OwnerMismatch,
         );
+    }
 
-        execute_upgrade.receipt.status
-    };
-
-    msg!("receipt exists: {:?}", status);
     handle_upgrade(accounts, bumps)
 }
 
@@ -66,7 +71,9 @@ where
         payer,
         admin,
         receipt,
+        proposal,
         buffer,
+        rollback_buffer,
         program_data,
         program,
         bpf_loader_upgradeable_program,
@@ -79,12 +86,44 @@ where
         upgrade_authority,
     } = admin;
 
+    // Keep the rollback buffer from the first uncommitted upgrade. Otherwise the rollback buffer
+    // must hold the program bytes currently deployed.
+    let rollback_buffer = match receipt.status {
+        UpgradeStatus::Uncommitted {
+            rollback_buffer, ..
+        } => rollback_buffer,
+        UpgradeStatus::None => {
+            let rollback_buffer = rollback_buffer
+                .as_ref()
+                .ok_or_else(|| error!(UpgradeManagerError::MissingRollbackBuffer))?;
+            require!(
+                rollback_buffer_matches(rollback_buffer, &program_data.to_account_info())?,
+                UpgradeManagerError::RollbackBufferMismatch
+            );
+
+            // The upgrade authority must own the rollback buffer so it can restore or close it.
+            bpf_loader_upgradeable::set_buffer_authority_checked(CpiContext::new_with_signer(
+                bpf_loader_upgradeable_program.to_account_info(),
+                bpf_loader_upgradeable::SetBufferAuthorityChecked {
+                    buffer: rollback_buffer.to_account_info(),
+                    current_authority: owner.to_account_info(),
+                    new_authority: upgrade_authority.to_account_info(),
+                },
+                &[crate::UPGRADE_AUTHORITY_SIGNER_SEEDS],
+            ))?;
+
+            Some(rollback_buffer.key())
+        }
+    };
+
     receipt.set_inner(UpgradeReceipt {
         bump: bumps.receipt,
         program_data_bump: bumps.program_data,
         owner: owner.key(),
         status: UpgradeStatus::Uncommitted {
             buffer: buffer.key(),
+            buffer_hash: proposal.buffer_hash,
+            rollback_buffer,
             slot: Clock::get().unwrap().slot,
         },
     });
@@ -114,3 +153,132 @@ where
         &[crate::UPGRADE_AUTHORITY_SIGNER_SEEDS],
     ))
 }
+
+/// Compute the SHA-256 hash of the program ELF written to a BPF Loader Upgradeable buffer, which
+/// excludes the buffer's metadata and any bytes beyond the ELF's length.
+pub fn buffer_hash(buffer: &AccountInfo) -> Result<[u8; 32]> {
+    let data = buffer.try_borrow_data()?;
+    let elf = program_elf(&data, UpgradeableLoaderState::size_of_buffer_metadata())?;
+
+    Ok(hash::hash(elf).to_bytes())
+}
+
+/// Check whether the program ELF written to a rollback buffer is the one deployed in a program
+/// data account. The program data is padded to its max data length, so only the ELF bytes are
+/// compared. Comparing bytes directly instead of hashing both accounts keeps the compute cost of
+/// executing an upgrade down to hashing the proposed buffer.
+pub fn rollback_buffer_matches(
+    rollback_buffer: &AccountInfo,
+    program_data: &AccountInfo,
+) -> Result<bool> {
+    let rollback_data = rollback_buffer.try_borrow_data()?;
+    let rollback_elf = program_elf(
+        &rollback_data,
+        UpgradeableLoaderState::size_of_buffer_metadata(),
+    )?;
+
+    let program_data = program_data.try_borrow_data()?;
+    let deployed_elf = program_elf(
+        &program_data,
+        UpgradeableLoaderState::size_of_programdata_metadata(),
+    )?;
+
+    Ok(rollback_elf.len() == deployed_elf.len()
+        && sol_memcmp(rollback_elf, deployed_elf, rollback_elf.len()) == 0)
+}
+
+/// Program bytes following the account's metadata, truncated to the ELF's length.
+fn program_elf(data: &[u8], metadata_len: usize) -> Result<&[u8]> {
+    data.get(metadata_len..)
+        .and_then(|program_bytes| program_bytes.get(..elf_len(program_bytes)?))
+        .ok_or_else(|| error!(UpgradeManagerError::InvalidBuffer))
+}
+
+/// Length of a 64-bit little-endian ELF, which ends with its section header table.
+fn elf_len(program_bytes: &[u8]) -> Option<usize> {
+    const ELF_MAGIC: &[u8] = b"\x7fELF";
+    const ELF_CLASS_64: u8 = 2;
+    const ELF_DATA_LSB: u8 = 1;
+
+    if program_bytes.get(..4)? != ELF_MAGIC
+        || *program_bytes.get(4)? != ELF_CLASS_64
+        || *program_bytes.get(5)? != ELF_DATA_LSB
+    {
+        return None;
+    }
+
+    let section_header_offset = u64::from_le_bytes(program_bytes.get(0x28..0x30)?.try_into().ok()?);
+    let section_header_size = u16::from_le_bytes(program_bytes.get(0x3a..0x3c)?.try_into().ok()?);
+    let section_header_count = u16::from_le_bytes(program_bytes.get(0x3c..0x3e)?.try_into().ok()?);
+
+    usize::try_from(section_header_offset).ok()?.checked_add(
+        usize::from(section_header_size).checked_mul(usize::from(section_header_count))?,
+    )
+}
+
+/// Close the rollback buffer recorded in the receipt (if any) and send its lamports to the
+/// recipient. This should be called when an upgrade is committed.
+pub fn close_rollback_buffer(commit_upgrade: &CommitUpgrade) -> Result<()> {
+    let CommitUpgrade {
+        admin,
+        recipient,
+        rollback_buffer,
+        bpf_loader_upgradeable_program,
+        ..
+    } = commit_upgrade;
+
+    // The commit upgrade composite checks that the rollback buffer matches the receipt.
+    match rollback_buffer {
+        Some(rollback_buffer) => invoke_signed(
+            &loader::close_any(
+                &rollback_buffer.key(),
+                &recipient.key(),
+                Some(&admin.upgrade_authority.key()),
+                None,
+            ),
+            &[
+                rollback_buffer.to_account_info(),
+                recipient.to_account_info(),
+                admin.upgrade_authority.to_account_info(),
+                bpf_loader_upgradeable_program.to_account_info(),
+            ],
+            &[crate::UPGRADE_AUTHORITY_SIGNER_SEEDS],
+        )
+        .map_err(Into::into),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dummy_elf(section_header_offset: u64, len: usize) -> Vec<u8> {
+        let mut elf = vec![0; len];
+        elf[..6].copy_from_slice(b"\x7fELF\x02\x01");
+        elf[0x28..0x30].copy_from_slice(&section_header_offset.to_le_bytes());
+        elf[0x3a..0x3c].copy_from_slice(&64u16.to_le_bytes());
+        elf[0x3c..0x3e].copy_from_slice(&2u16.to_le_bytes());
+        elf
+    }
+
+    #[test]
+    fn program_elf_excludes_padding() {
+        let mut data = vec![9; 4];
+        data.extend(dummy_elf(0x100, 0x180));
+        data.extend([0; 32]);
+
+        let elf = program_elf(&data, 4).unwrap();
+        assert_eq!(elf.len(), 0x180);
+        assert_eq!(elf, &data[4..0x184]);
+    }
+
+    #[test]
+    fn program_elf_rejects_truncated_or_invalid_elf() {
+        assert!(program_elf(&dummy_elf(0x100, 0x17f), 0).is_err());
+
+        let mut elf = dummy_elf(0x100, 0x180);
+        elf[4] = 1;
+        assert!(program_elf(&elf, 0).is_err());
+    }
+}